@@ -0,0 +1,107 @@
+use std::time::{Duration, Instant};
+
+/// Delay between the flips of neighbouring tiles.
+const FLIP_STAGGER: Duration = Duration::from_millis(200);
+const FLIP: Duration = Duration::from_millis(300);
+const BOUNCE_STAGGER: Duration = Duration::from_millis(80);
+const BOUNCE: Duration = Duration::from_millis(240);
+const SHAKE: Duration = Duration::from_millis(420);
+const SHAKE_OFFSETS: [i16; 7] = [-2, 2, -2, 2, -1, 1, 0];
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum Kind {
+    /// Tiles flip one after another to show their colours, and bounce
+    /// afterwards if `won`.
+    Reveal {
+        won: bool,
+    },
+    Shake,
+}
+
+/// A time-based animation of one board row. Everything is computed from the
+/// start time, so frames can be drawn at any rate.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Animation {
+    kind: Kind,
+    row: usize,
+    word_len: usize,
+    started: Instant,
+}
+
+/// How to draw one tile at a point in an animation.
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub struct TileEffect {
+    /// The tile hasn't flipped yet and must not show its colour.
+    pub hidden: bool,
+    /// The tile is edge-on halfway through its flip.
+    pub flipping: bool,
+    pub offset_x: i16,
+    pub offset_y: i16,
+}
+
+impl Animation {
+    pub fn reveal(row: usize, word_len: usize, won: bool, now: Instant) -> Self {
+        Self {
+            kind: Kind::Reveal { won },
+            row,
+            word_len,
+            started: now,
+        }
+    }
+
+    pub fn shake(row: usize, word_len: usize, now: Instant) -> Self {
+        Self {
+            kind: Kind::Shake,
+            row,
+            word_len,
+            started: now,
+        }
+    }
+
+    /// The board row being animated.
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    pub fn is_finished(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.started) >= self.duration()
+    }
+
+    fn duration(&self) -> Duration {
+        let last = self.word_len.saturating_sub(1) as u32;
+        match self.kind {
+            Kind::Reveal { won: false } => FLIP_STAGGER * last + FLIP,
+            Kind::Reveal { won: true } => {
+                FLIP_STAGGER * last + FLIP + BOUNCE_STAGGER * last + BOUNCE
+            }
+            Kind::Shake => SHAKE,
+        }
+    }
+
+    pub fn tile(&self, column: usize, now: Instant) -> TileEffect {
+        let t = now.saturating_duration_since(self.started);
+        match self.kind {
+            Kind::Reveal { won } => {
+                let flip_start = FLIP_STAGGER * column as u32;
+                let mut effect = TileEffect {
+                    hidden: t < flip_start + FLIP / 2,
+                    flipping: t >= flip_start + FLIP / 3 && t < flip_start + FLIP * 2 / 3,
+                    ..TileEffect::default()
+                };
+                let last = self.word_len.saturating_sub(1) as u32;
+                let bounce_start = FLIP_STAGGER * last + FLIP + BOUNCE_STAGGER * column as u32;
+                if won && t >= bounce_start && t < bounce_start + BOUNCE {
+                    effect.offset_y = -1;
+                }
+                effect
+            }
+            Kind::Shake => {
+                let step = t.as_millis() * SHAKE_OFFSETS.len() as u128 / SHAKE.as_millis();
+                TileEffect {
+                    offset_x: SHAKE_OFFSETS.get(step as usize).copied().unwrap_or(0),
+                    ..TileEffect::default()
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::time::Instant;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::widgets::Widget;
+
+use crate::animation::{Animation, TileEffect};
+use crate::game_logic::Element;
+use crate::theme::{self, Theme};
+
+/// The grid of scored guesses, the row being typed and empty rows for the
+/// guesses that are left. Tiles are drawn with borders when there is room and
+/// as single lines otherwise, down to one cell per letter for long words in
+/// narrow terminals; with unlimited guesses the latest rows are shown.
+#[derive(Debug, Clone)]
+pub struct Board<'a> {
+    guesses: &'a [Vec<Element>],
+    word_len: usize,
+    input: Option<&'a str>,
+    input_valid: bool,
+    max_guesses: Option<usize>,
+    animation: Option<(Animation, Instant)>,
+    theme: &'a Theme,
+}
+
+/// How tiles are drawn, from the roomiest to the tightest.
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum TileSize {
+    Bordered,
+    Compact,
+    /// Compact tiles without the gap between them.
+    Packed,
+    Letter,
+}
+
+impl TileSize {
+    fn width(self) -> u16 {
+        match self {
+            TileSize::Bordered => 5,
+            TileSize::Compact | TileSize::Packed => 3,
+            TileSize::Letter => 1,
+        }
+    }
+
+    fn height(self) -> u16 {
+        match self {
+            TileSize::Bordered => 3,
+            _ => 1,
+        }
+    }
+
+    fn gap(self) -> u16 {
+        match self {
+            TileSize::Bordered | TileSize::Compact => 1,
+            TileSize::Packed | TileSize::Letter => 0,
+        }
+    }
+
+    /// The width of a row of `columns` tiles.
+    fn row_width(self, columns: u16) -> u16 {
+        (columns * (self.width() + self.gap())).saturating_sub(self.gap())
+    }
+}
+
+enum Tile<'a> {
+    Scored(&'a Element),
+    Typed(char),
+    Invalid(char),
+    Empty,
+}
+
+impl<'a> Board<'a> {
+    pub fn new(guesses: &'a [Vec<Element>], word_len: usize) -> Self {
+        Self {
+            guesses,
+            word_len,
+            input: None,
+            input_valid: true,
+            max_guesses: None,
+            animation: None,
+            theme: &theme::DEFAULT,
+        }
+    }
+
+    /// Shows `input` in the row after the last guess.
+    pub fn input(mut self, input: &'a str) -> Self {
+        self.input = Some(input);
+        self
+    }
+
+    /// Marks the typed letters when `false`, for input that can't become an
+    /// allowed guess.
+    pub fn input_valid(mut self, valid: bool) -> Self {
+        self.input_valid = valid;
+        self
+    }
+
+    /// Pads the board with empty rows up to `max_guesses`.
+    pub fn max_guesses(mut self, max_guesses: Option<usize>) -> Self {
+        self.max_guesses = max_guesses;
+        self
+    }
+
+    /// Draws the board as it looks at `now` in `animation`.
+    pub fn animation(mut self, animation: Animation, now: Instant) -> Self {
+        self.animation = Some((animation, now));
+        self
+    }
+
+    pub fn theme(mut self, theme: &'a Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    fn row_count(&self) -> usize {
+        let played = self.guesses.len() + usize::from(self.input.is_some());
+        self.max_guesses.map_or(played, |max| max.max(played))
+    }
+
+    fn tile(&self, row: usize, column: usize) -> Tile<'a> {
+        if let Some(guess) = self.guesses.get(row) {
+            return guess.get(column).map_or(Tile::Empty, Tile::Scored);
+        }
+        match self.input {
+            Some(input) if row == self.guesses.len() => match input.chars().nth(column) {
+                Some(c) if self.input_valid => Tile::Typed(c),
+                Some(c) => Tile::Invalid(c),
+                None => Tile::Empty,
+            },
+            _ => Tile::Empty,
+        }
+    }
+}
+
+impl Tile<'_> {
+    fn label(&self, theme: &Theme, size: TileSize) -> String {
+        match (self, size) {
+            (Tile::Scored(e), TileSize::Letter) => e.c.to_ascii_uppercase().to_string(),
+            (Tile::Typed(c) | Tile::Invalid(c), TileSize::Letter) => {
+                c.to_ascii_uppercase().to_string()
+            }
+            (Tile::Scored(e), _) => theme.label(e.c, Some(e.status)),
+            (Tile::Typed(c) | Tile::Invalid(c), _) => theme.label(*c, None),
+            (Tile::Empty, TileSize::Bordered) => "   ".to_string(),
+            (Tile::Empty, TileSize::Letter) => "·".to_string(),
+            (Tile::Empty, _) => " · ".to_string(),
+        }
+    }
+
+    fn style(&self, theme: &Theme) -> Style {
+        match self {
+            Tile::Scored(e) => theme.hint_style(e.status),
+            Tile::Typed(_) => theme.typed_style(),
+            Tile::Invalid(_) => theme.invalid_style(),
+            Tile::Empty => theme.empty_style(),
+        }
+    }
+
+    fn render(&self, x: u16, y: u16, size: TileSize, theme: &Theme, buf: &mut Buffer) {
+        let style = self.style(theme);
+        let label = self.label(theme, size);
+        if size == TileSize::Bordered {
+            buf.set_string(x, y, "┌───┐", style);
+            buf.set_string(x, y + 1, format!("│{}│", label), style);
+            buf.set_string(x, y + 2, "└───┘", style);
+        } else {
+            buf.set_string(x, y, label, style);
+        }
+    }
+
+    /// The tile seen edge-on in the middle of a flip.
+    fn render_edge(&self, x: u16, y: u16, size: TileSize, theme: &Theme, buf: &mut Buffer) {
+        let style = self.style(theme);
+        match size {
+            TileSize::Bordered => buf.set_string(x, y + 1, "─────", style),
+            TileSize::Letter => buf.set_string(x, y, "─", style),
+            _ => buf.set_string(x, y, " ─ ", style),
+        };
+    }
+}
+
+impl Widget for Board<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let rows = self.row_count();
+        let columns = self.word_len as u16;
+        let bordered = TileSize::Bordered.row_width(columns) <= area.width
+            && rows * TileSize::Bordered.height() as usize <= area.height as usize;
+        let size = if bordered {
+            TileSize::Bordered
+        } else {
+            [TileSize::Compact, TileSize::Packed]
+                .into_iter()
+                .find(|size| size.row_width(columns) <= area.width)
+                .unwrap_or(TileSize::Letter)
+        };
+        let (tile_width, tile_height) = (size.width(), size.height());
+
+        let visible = rows.min((area.height / tile_height) as usize);
+        let first = rows - visible;
+        let width = size.row_width(columns);
+        let left = area.left() + area.width.saturating_sub(width) / 2;
+        let top = area.top() + (area.height - visible as u16 * tile_height) / 2;
+
+        for (i, row) in (first..rows).enumerate() {
+            let y = top + i as u16 * tile_height;
+            for column in 0..self.word_len {
+                let x = left + column as u16 * (tile_width + size.gap());
+                if x + tile_width > area.right() {
+                    break;
+                }
+
+                let effect = match self.animation {
+                    Some((animation, now)) if animation.row() == row => animation.tile(column, now),
+                    _ => TileEffect::default(),
+                };
+                let tile = match self.tile(row, column) {
+                    Tile::Scored(e) if effect.hidden => Tile::Typed(e.c),
+                    tile => tile,
+                };
+                let x = x
+                    .saturating_add_signed(effect.offset_x)
+                    .clamp(area.left(), area.right() - tile_width);
+                let y = if size == TileSize::Bordered {
+                    y.saturating_add_signed(effect.offset_y).max(area.top())
+                } else {
+                    y
+                };
+                if effect.flipping {
+                    tile.render_edge(x, y, size, self.theme, buf);
+                } else {
+                    tile.render(x, y, size, self.theme, buf);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use std::hint::black_box;
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use wordle_rust::game_logic::{check_chars, check_word, elements_from_pattern, Knowledge};
+use wordle_rust::pattern::{Pattern, PatternMatrix};
+use wordle_rust::solver::Solver;
+use wordle_rust::stats::StatsStore;
+use wordle_rust::word::{Feedback, Word};
+use wordle_rust::WordLists;
+
+/// Exit status of `check` for a word that isn't in the word lists.
+const EXIT_NOT_FOUND: u8 = 1;
+
+/// Candidates listed after each step of `solve`.
+const SHOWN_CANDIDATES: usize = 20;
+
+/// Guesses suggested after each step of `solve`.
+const SUGGESTIONS: usize = 5;
+
+type Result = std::result::Result<ExitCode, String>;
+
+pub fn score(guess: &str, solution: &str, colorblind: bool) -> Result {
+    let guess = guess.to_lowercase();
+    let solution = solution.to_lowercase();
+    if guess.chars().count() != solution.chars().count() {
+        return Err(format!(
+            "`{}` and `{}` have different lengths",
+            guess, solution
+        ));
+    }
+
+    let elements = check_word(&guess, &solution);
+    let squares: String = elements
+        .iter()
+        .map(|e| e.status.square(colorblind))
+        .collect();
+    let codes: String = elements.iter().map(|e| e.status.code()).collect();
+    println!("{}  {}  {}", guess.to_uppercase(), squares, codes);
+    Ok(ExitCode::SUCCESS)
+}
+
+pub fn stats(json: bool) -> Result {
+    let store = StatsStore::open_default().ok_or("no data directory to read statistics from")?;
+    let stats = store
+        .load()
+        .map_err(|e| format!("{}: {}", store.path().display(), e))?;
+
+    if json {
+        let text = serde_json::to_string_pretty(&stats).map_err(|e| e.to_string())?;
+        println!("{}", text);
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    println!("Played: {}   Win %: {}", stats.played, stats.win_rate());
+    println!(
+        "Current streak: {}   Max streak: {}",
+        stats.current_streak, stats.max_streak
+    );
+    println!();
+    println!("Guess distribution:");
+    let most = stats.guess_distribution.iter().copied().max().unwrap_or(0);
+    for (i, &count) in stats.guess_distribution.iter().enumerate() {
+        let width = (count * 30).checked_div(most).unwrap_or(0) as usize;
+        println!("{:>2} {} {}", i + 1, "█".repeat(width.max(1)), count);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Prints whether `word` is an answer or allowed guess. A pattern with `?`
+/// prints every allowed guess it matches, answers marked with `*`.
+pub fn check(word: &str, words: &WordLists) -> Result {
+    let word = word.to_lowercase();
+    if word.contains('?') {
+        let matches = words.matching(&word);
+        for w in &matches {
+            let marker = if words.answers.contains_word(*w) {
+                "*"
+            } else {
+                ""
+            };
+            println!("{}{}", w, marker);
+        }
+        if matches.is_empty() {
+            println!("{}: no matching words", word);
+            return Ok(ExitCode::from(EXIT_NOT_FOUND));
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if words.answers.contains(&word) {
+        println!("{}: possible answer", word);
+    } else if words.guesses.contains(&word) {
+        println!("{}: allowed guess", word);
+    } else {
+        println!("{}: not in word list", word);
+        return Ok(ExitCode::from(EXIT_NOT_FOUND));
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Reads guesses made in another game along with the colours they got, and
+/// suggests the next guess.
+pub fn solve(words: &WordLists, word_len: usize) -> Result {
+    let mut solver = Solver::from_word_lists(words, word_len);
+    if solver.candidates().is_empty() {
+        return Err(format!("no answers with {} letters", word_len));
+    }
+    if word_len <= Pattern::MAX_LEN {
+        let (guesses, answers) = (solver.guesses(), solver.answers());
+        let matrix = match PatternMatrix::default_path(word_len) {
+            Some(path) => PatternMatrix::load_or_build(&path, guesses, answers),
+            None => PatternMatrix::build(guesses, answers),
+        };
+        solver = solver.with_matrix(matrix.map_err(|e| e.to_string())?);
+    }
+
+    println!(
+        "Enter each guess and the colours it got, e.g. `crane g..y.`\n\
+        (g = green, y = yellow, . = grey). An empty line quits."
+    );
+    let mut stdin = io::stdin().lock();
+    loop {
+        print_suggestions(&solver);
+        print!("> ");
+        io::stdout().flush().map_err(|e| e.to_string())?;
+
+        let mut line = String::new();
+        if stdin.read_line(&mut line).map_err(|e| e.to_string())? == 0 {
+            break;
+        }
+        let mut parts = line.split_whitespace();
+        let (Some(guess), Some(pattern), None) = (parts.next(), parts.next(), parts.next()) else {
+            if line.trim().is_empty() {
+                break;
+            }
+            println!("Expected a guess and its colours, e.g. `crane g..y.`");
+            continue;
+        };
+
+        let guess = guess.to_lowercase();
+        let Some(word) = Word::new(&guess).filter(|w| w.len() == word_len) else {
+            println!("`{}` isn't a word of {} letters", guess, word_len);
+            continue;
+        };
+        let Some(elements) = elements_from_pattern(&guess, pattern) else {
+            println!("Colours must be {} of g, y and .", word_len);
+            continue;
+        };
+
+        let feedback = Feedback::from_statuses(elements.iter().map(|e| e.status));
+        // Most likely a typo, so the earlier steps are kept.
+        if !solver.try_apply(word, feedback.expect("checked against the word length")) {
+            println!("No answer fits those colours, check them and try again.");
+            continue;
+        }
+        if let [answer] = solver.candidates()[..] {
+            println!("The answer is {}.", answer.to_string().to_uppercase());
+            break;
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn print_suggestions(solver: &Solver) {
+    let candidates = solver.candidates();
+    let shown = &candidates[..candidates.len().min(SHOWN_CANDIDATES)];
+    let shown: Vec<String> = shown.iter().map(Word::to_string).collect();
+    print!("{} possible: {}", candidates.len(), shown.join(" "));
+    if candidates.len() > shown.len() {
+        print!(" … and {} more", candidates.len() - shown.len());
+    }
+    println!();
+
+    println!("Best guesses (* could be the answer):");
+    for suggestion in solver.best_guesses(SUGGESTIONS) {
+        let marker = if suggestion.is_candidate { "*" } else { " " };
+        println!(
+            "  {}{} {:.2} bits",
+            suggestion.word.to_string().to_uppercase(),
+            marker,
+            suggestion.entropy
+        );
+    }
+}
+
+/// Scores every allowed guess against the first `solutions` answers, as
+/// strings and as packed words, and filters all answers with as many results.
+/// Words that fit a [`Pattern`] are also scored into a full pattern matrix.
+pub fn bench(words: &WordLists, word_len: usize, solutions: usize) -> Result {
+    if solutions == 0 {
+        return Err("--solutions must be at least 1".to_string());
+    }
+    let answers = words.answers.packed(word_len);
+    let solutions = &answers[..solutions.min(answers.len())];
+    let guesses: Vec<Word> = answers
+        .iter()
+        .chain(words.guesses.packed(word_len))
+        .copied()
+        .collect();
+    if solutions.is_empty() {
+        return Err(format!("no answers with {} letters", word_len));
+    }
+    let pairs = guesses.len() * solutions.len();
+
+    let guess_text: Vec<String> = guesses.iter().map(Word::to_string).collect();
+    let solution_text: Vec<String> = solutions.iter().map(Word::to_string).collect();
+    let started = Instant::now();
+    for solution in &solution_text {
+        for guess in &guess_text {
+            black_box(check_chars(black_box(guess), solution));
+        }
+    }
+    let chars_elapsed = started.elapsed();
+    report("check_chars", pairs, chars_elapsed);
+
+    let started = Instant::now();
+    for solution in &solution_text {
+        for guess in &guess_text {
+            black_box(check_word(black_box(guess), solution));
+        }
+    }
+    report("check_word", pairs, started.elapsed());
+
+    let started = Instant::now();
+    for &solution in solutions {
+        for &guess in &guesses {
+            black_box(black_box(guess).score(solution));
+        }
+    }
+    let score_elapsed = started.elapsed();
+    report("Word::score", pairs, score_elapsed);
+    println!(
+        "Word::score is {:.1}x faster than check_chars",
+        chars_elapsed.as_secs_f64() / score_elapsed.as_secs_f64().max(f64::EPSILON)
+    );
+
+    if word_len <= Pattern::MAX_LEN {
+        let started = Instant::now();
+        let matrix = PatternMatrix::build(&guesses, answers).map_err(|e| e.to_string())?;
+        let pairs = matrix.guess_count() * matrix.answer_count();
+        report("PatternMatrix::build", pairs, started.elapsed());
+    }
+
+    let knowledge: Vec<Knowledge> = guess_text
+        .iter()
+        .zip(&solution_text)
+        .map(|(guess, solution)| Knowledge::from_history(&[check_word(guess, solution)]))
+        .collect();
+    let answer_text: Vec<String> = answers.iter().map(Word::to_string).collect();
+    let checks = knowledge.len() * answers.len();
+
+    let started = Instant::now();
+    for k in &knowledge {
+        black_box(answer_text.iter().filter(|a| k.is_consistent(a)).count());
+    }
+    report("Knowledge::is_consistent", checks, started.elapsed());
+
+    let started = Instant::now();
+    for k in &knowledge {
+        black_box(answers.iter().filter(|&&a| k.matches(a)).count());
+    }
+    report("Knowledge::matches", checks, started.elapsed());
+    Ok(ExitCode::SUCCESS)
+}
+
+fn report(name: &str, count: usize, elapsed: Duration) {
+    let per_call = elapsed.as_nanos() / count.max(1) as u128;
+    println!(
+        "{:<26} {:>10} calls in {:>8.1?}  ({} ns/call)",
+        name, count, elapsed, per_call
+    );
+}
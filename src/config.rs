@@ -0,0 +1,301 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::{Difficulty, GameConfig, DEFAULT_MAX_GUESSES, DEFAULT_WORD_LENGTH};
+use crate::keyboard::KeyboardLayout;
+use crate::word_list::{MAX_WORD_LENGTH, MIN_WORD_LENGTH};
+
+/// The largest guess limit offered on the settings screen before "unlimited".
+const MAX_GUESS_CHOICE: usize = 12;
+
+/// Settings read from `config.toml`. Every key is optional:
+///
+/// ```toml
+/// word_length = 6
+/// max_guesses = "unlimited"
+/// difficulty = "Hard"
+/// theme = "high-contrast"
+/// answers = "/usr/share/dict/answers.txt"
+/// keyboard_layout = "azerty"
+/// animations = false
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub word_length: usize,
+    /// `None` allows unlimited guesses, written as `"unlimited"`.
+    #[serde(with = "guess_limit")]
+    pub max_guesses: Option<NonZeroUsize>,
+    pub difficulty: Difficulty,
+    pub theme: String,
+    /// Replaces the built-in answer list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answers: Option<PathBuf>,
+    /// Replaces the built-in list of extra allowed guesses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guesses: Option<PathBuf>,
+    pub keyboard_layout: KeyboardLayout,
+    pub animations: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            word_length: DEFAULT_WORD_LENGTH,
+            max_guesses: Some(DEFAULT_MAX_GUESSES),
+            difficulty: Difficulty::Normal,
+            theme: "default".to_string(),
+            answers: None,
+            guesses: None,
+            keyboard_layout: KeyboardLayout::Qwerty,
+            animations: true,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    Serialize(toml::ser::Error),
+    Invalid(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "could not access config: {}", e),
+            ConfigError::Parse(e) => write!(f, "invalid config: {}", e),
+            ConfigError::Serialize(e) => write!(f, "could not write config: {}", e),
+            ConfigError::Invalid(message) => write!(f, "invalid config: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(e: toml::de::Error) -> Self {
+        ConfigError::Parse(e)
+    }
+}
+
+impl From<toml::ser::Error> for ConfigError {
+    fn from(e: toml::ser::Error) -> Self {
+        ConfigError::Serialize(e)
+    }
+}
+
+impl Config {
+    /// `<config dir>/wordle-rust/config.toml`, if the platform has a config
+    /// directory.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("wordle-rust").join("config.toml"))
+    }
+
+    pub fn from_toml(text: &str) -> Result<Self, ConfigError> {
+        let config: Config = toml::from_str(text)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// A missing file gives the default settings.
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::from_toml(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ConfigError> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp = path.with_extension("toml.tmp");
+        fs::write(&tmp, toml::to_string(self)?)?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if !(MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&self.word_length) {
+            return Err(ConfigError::Invalid(format!(
+                "word_length must be between {} and {}, found {}",
+                MIN_WORD_LENGTH, MAX_WORD_LENGTH, self.word_length
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn game_config(&self) -> GameConfig {
+        GameConfig {
+            word_len: self.word_length,
+            max_guesses: self.max_guesses,
+            difficulty: self.difficulty,
+        }
+    }
+
+    pub fn value_text(&self, setting: Setting) -> String {
+        match setting {
+            Setting::WordLength => self.word_length.to_string(),
+            Setting::MaxGuesses => self
+                .max_guesses
+                .map_or("unlimited".to_string(), |n| n.to_string()),
+            Setting::Difficulty => match self.difficulty {
+                Difficulty::Normal => "normal",
+                Difficulty::Hard => "hard",
+                Difficulty::UltraHard => "ultra hard",
+            }
+            .to_string(),
+            Setting::Theme => self.theme.clone(),
+            Setting::KeyboardLayout => self.keyboard_layout.name().to_string(),
+            Setting::Animations => if self.animations { "on" } else { "off" }.to_string(),
+        }
+    }
+
+    /// Changes `setting` to its next value, or the previous one if `forward`
+    /// is false. Only the given word lengths and themes are offered.
+    pub fn step(
+        &mut self,
+        setting: Setting,
+        forward: bool,
+        word_lengths: &[usize],
+        themes: &[&str],
+    ) {
+        match setting {
+            Setting::WordLength => {
+                self.word_length = step_in(word_lengths, &self.word_length, forward);
+            }
+            Setting::MaxGuesses => {
+                let choices: Vec<Option<NonZeroUsize>> = (1..=MAX_GUESS_CHOICE)
+                    .map(NonZeroUsize::new)
+                    .chain([None])
+                    .collect();
+                self.max_guesses = step_in(&choices, &self.max_guesses, forward);
+            }
+            Setting::Difficulty => {
+                let choices = [Difficulty::Normal, Difficulty::Hard, Difficulty::UltraHard];
+                self.difficulty = step_in(&choices, &self.difficulty, forward);
+            }
+            Setting::Theme => {
+                self.theme = step_in(themes, &self.theme.as_str(), forward).to_string();
+            }
+            Setting::KeyboardLayout => {
+                self.keyboard_layout =
+                    step_in(&KeyboardLayout::ALL, &self.keyboard_layout, forward);
+            }
+            Setting::Animations => self.animations = !self.animations,
+        }
+    }
+}
+
+/// The value after (or before) `current` in `choices`, wrapping around. A
+/// value that isn't one of the choices moves to the first choice.
+fn step_in<T: PartialEq + Clone>(choices: &[T], current: &T, forward: bool) -> T {
+    let Some(i) = choices.iter().position(|c| c == current) else {
+        return choices.first().unwrap_or(current).clone();
+    };
+    let next = if forward {
+        (i + 1) % choices.len()
+    } else {
+        (i + choices.len() - 1) % choices.len()
+    };
+    choices[next].clone()
+}
+
+/// The settings that can be changed on the settings screen.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Setting {
+    WordLength,
+    MaxGuesses,
+    Difficulty,
+    Theme,
+    KeyboardLayout,
+    Animations,
+}
+
+impl Setting {
+    pub const ALL: [Setting; 6] = [
+        Setting::WordLength,
+        Setting::MaxGuesses,
+        Setting::Difficulty,
+        Setting::Theme,
+        Setting::KeyboardLayout,
+        Setting::Animations,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Setting::WordLength => "Word length",
+            Setting::MaxGuesses => "Guesses",
+            Setting::Difficulty => "Difficulty",
+            Setting::Theme => "Theme",
+            Setting::KeyboardLayout => "Keyboard",
+            Setting::Animations => "Animations",
+        }
+    }
+}
+
+/// A positive number of guesses, or `"unlimited"`.
+mod guess_limit {
+    use std::fmt;
+    use std::num::NonZeroUsize;
+
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(limit: &Option<NonZeroUsize>, s: S) -> Result<S::Ok, S::Error> {
+        match limit {
+            Some(n) => s.serialize_u64(n.get() as u64),
+            None => s.serialize_str("unlimited"),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<NonZeroUsize>, D::Error> {
+        d.deserialize_any(GuessLimitVisitor)
+    }
+
+    struct GuessLimitVisitor;
+
+    impl Visitor<'_> for GuessLimitVisitor {
+        type Value = Option<NonZeroUsize>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a positive number or \"unlimited\"")
+        }
+
+        fn visit_i64<E: de::Error>(self, n: i64) -> Result<Self::Value, E> {
+            match usize::try_from(n).ok().and_then(NonZeroUsize::new) {
+                Some(n) => Ok(Some(n)),
+                None => Err(E::invalid_value(de::Unexpected::Signed(n), &self)),
+            }
+        }
+
+        fn visit_u64<E: de::Error>(self, n: u64) -> Result<Self::Value, E> {
+            match usize::try_from(n).ok().and_then(NonZeroUsize::new) {
+                Some(n) => Ok(Some(n)),
+                None => Err(E::invalid_value(de::Unexpected::Unsigned(n), &self)),
+            }
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+            if s.eq_ignore_ascii_case("unlimited") {
+                Ok(None)
+            } else {
+                Err(E::invalid_value(de::Unexpected::Str(s), &self))
+            }
+        }
+    }
+}
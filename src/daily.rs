@@ -0,0 +1,30 @@
+use chrono::{Local, NaiveDate};
+
+use crate::rng::SplitMix64;
+
+const DAILY_SEED: u64 = 0x5752_444c;
+
+/// The day of puzzle number 0.
+pub fn epoch() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()
+}
+
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+/// Returns `None` for dates before the epoch.
+pub fn puzzle_number(date: NaiveDate) -> Option<u32> {
+    u32::try_from((date - epoch()).num_days()).ok()
+}
+
+/// Picks the solution for a puzzle number. Every pass through `answers` uses
+/// its own fixed shuffle, so no word repeats until all have been used.
+pub fn solution<T: AsRef<str>>(answers: &[T], number: u32) -> &str {
+    let number = number as usize;
+    let cycle = (number / answers.len()) as u64;
+
+    let mut order: Vec<usize> = (0..answers.len()).collect();
+    SplitMix64::new(DAILY_SEED ^ cycle).shuffle(&mut order);
+    answers[order[number % answers.len()]].as_ref()
+}
@@ -0,0 +1,285 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::word::Word;
+use crate::word_index::WordIndex;
+use crate::word_list::{self, MAX_WORD_LENGTH, MIN_WORD_LENGTH};
+
+/// A word list bucketed by word length.
+#[derive(Debug, Clone)]
+pub struct Dictionary {
+    buckets: BTreeMap<usize, Vec<String>>,
+    packed: BTreeMap<usize, Vec<Word>>,
+    /// One index per word length, so prefix queries only see words that
+    /// could be guessed in the same game.
+    indexes: BTreeMap<usize, WordIndex>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum LineErrorKind {
+    WrongLength { found: usize },
+    NonAlphabetic,
+    Duplicate { first_line: usize },
+}
+
+/// A rejected entry. `line` is 1-based and counts file lines for text files
+/// and array entries for JSON files.
+#[derive(PartialEq, Debug, Clone)]
+pub struct LineError {
+    pub line: usize,
+    pub word: String,
+    pub kind: LineErrorKind,
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: \"{}\" ", self.line, self.word)?;
+        match self.kind {
+            LineErrorKind::WrongLength { found } => write!(
+                f,
+                "has {} letters, expected {} to {}",
+                found, MIN_WORD_LENGTH, MAX_WORD_LENGTH
+            ),
+            LineErrorKind::NonAlphabetic => write!(f, "contains non-alphabetic characters"),
+            LineErrorKind::Duplicate { first_line } => {
+                write!(f, "is a duplicate of line {}", first_line)
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DictionaryError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    Empty,
+    InvalidLines(Vec<LineError>),
+}
+
+impl fmt::Display for DictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DictionaryError::Io(e) => write!(f, "could not read dictionary: {}", e),
+            DictionaryError::Json(e) => write!(f, "invalid JSON dictionary: {}", e),
+            DictionaryError::Empty => write!(f, "dictionary contains no words"),
+            DictionaryError::InvalidLines(errors) => {
+                write!(f, "dictionary has {} invalid entries:", errors.len())?;
+                for error in errors {
+                    write!(f, "\n  {}", error)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for DictionaryError {}
+
+impl From<io::Error> for DictionaryError {
+    fn from(e: io::Error) -> Self {
+        DictionaryError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for DictionaryError {
+    fn from(e: serde_json::Error) -> Self {
+        DictionaryError::Json(e)
+    }
+}
+
+impl Dictionary {
+    /// Validates every entry and collects all problems instead of stopping
+    /// at the first one. Words are lowercased.
+    pub fn from_entries<'a, I>(entries: I) -> Result<Self, DictionaryError>
+    where
+        I: IntoIterator<Item = (usize, &'a str)>,
+    {
+        let mut words: Vec<String> = vec![];
+        let mut first_seen: HashMap<String, usize> = HashMap::new();
+        let mut errors = vec![];
+
+        for (line, entry) in entries {
+            let word = entry.trim().to_lowercase();
+            let kind = if !word.chars().all(|c| c.is_ascii_alphabetic()) {
+                Some(LineErrorKind::NonAlphabetic)
+            } else if !(MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&word.len()) {
+                Some(LineErrorKind::WrongLength { found: word.len() })
+            } else if let Some(&first_line) = first_seen.get(&word) {
+                Some(LineErrorKind::Duplicate { first_line })
+            } else {
+                None
+            };
+
+            match kind {
+                Some(kind) => errors.push(LineError { line, word, kind }),
+                None => {
+                    first_seen.insert(word.clone(), line);
+                    words.push(word);
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(DictionaryError::InvalidLines(errors));
+        }
+        if words.is_empty() {
+            return Err(DictionaryError::Empty);
+        }
+
+        Ok(Self::from_words(words))
+    }
+
+    /// `words` must be valid, lowercase and unique.
+    fn from_words(words: Vec<String>) -> Self {
+        let mut buckets: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        let mut packed: BTreeMap<usize, Vec<Word>> = BTreeMap::new();
+        for word in words {
+            let w = Word::new(&word).expect("dictionary words are validated");
+            packed.entry(word.len()).or_default().push(w);
+            buckets.entry(word.len()).or_default().push(word);
+        }
+        let indexes = packed
+            .iter()
+            .map(|(&len, words)| (len, WordIndex::new(words.iter().copied())))
+            .collect();
+        Self {
+            buckets,
+            packed,
+            indexes,
+        }
+    }
+
+    /// One word per line. Blank lines and lines starting with `#` are skipped.
+    pub fn from_text(text: &str) -> Result<Self, DictionaryError> {
+        let entries = text
+            .lines()
+            .enumerate()
+            .map(|(i, l)| (i + 1, l))
+            .filter(|(_, l)| !l.trim().is_empty() && !l.trim_start().starts_with('#'));
+        Self::from_entries(entries)
+    }
+
+    /// A JSON array of strings.
+    pub fn from_json(text: &str) -> Result<Self, DictionaryError> {
+        let entries: Vec<String> = serde_json::from_str(text)?;
+        Self::from_entries(entries.iter().enumerate().map(|(i, w)| (i + 1, w.as_str())))
+    }
+
+    /// Loads a `.json` file as JSON and anything else as plain text.
+    pub fn load(path: &Path) -> Result<Self, DictionaryError> {
+        let text = fs::read_to_string(path)?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::from_json(&text),
+            _ => Self::from_text(&text),
+        }
+    }
+
+    pub fn builtin_answers() -> Self {
+        Self::from_builtin(word_list::answers)
+    }
+
+    pub fn builtin_guesses() -> Self {
+        Self::from_builtin(word_list::allowed_guesses)
+    }
+
+    fn from_builtin(list: fn(usize) -> &'static [&'static str]) -> Self {
+        let words = (MIN_WORD_LENGTH..=MAX_WORD_LENGTH)
+            .flat_map(list)
+            .map(|w| w.to_string())
+            .collect();
+        Self::from_words(words)
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        Word::new(word).is_some_and(|w| self.contains_word(w))
+    }
+
+    pub fn contains_word(&self, word: Word) -> bool {
+        self.indexes
+            .get(&word.len())
+            .is_some_and(|index| index.contains(word))
+    }
+
+    /// Whether some word of `word_len` letters starts with `prefix`.
+    pub fn has_prefix(&self, prefix: &str, word_len: usize) -> bool {
+        self.indexes
+            .get(&word_len)
+            .is_some_and(|index| index.has_prefix(prefix))
+    }
+
+    /// The words matching `pattern`, where `?` stands for any letter, in
+    /// alphabetical order.
+    pub fn matching(&self, pattern: &str) -> Vec<Word> {
+        self.indexes
+            .get(&pattern.chars().count())
+            .map_or(vec![], |index| index.matching(&pattern.to_lowercase()))
+    }
+
+    /// The words with `word_len` letters, in file order.
+    pub fn words(&self, word_len: usize) -> &[String] {
+        self.buckets.get(&word_len).map_or(&[], |b| b.as_slice())
+    }
+
+    /// [`Dictionary::words`] as packed words.
+    pub fn packed(&self, word_len: usize) -> &[Word] {
+        self.packed.get(&word_len).map_or(&[], |b| b.as_slice())
+    }
+
+    /// The word lengths that have at least one word.
+    pub fn lengths(&self) -> impl Iterator<Item = usize> + '_ {
+        self.buckets.keys().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.indexes.values().map(WordIndex::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indexes.is_empty()
+    }
+}
+
+/// The list solutions are drawn from and the list guesses are checked
+/// against. A guess is valid if it is in either list.
+#[derive(Debug, Clone)]
+pub struct WordLists {
+    pub answers: Dictionary,
+    pub guesses: Dictionary,
+}
+
+impl WordLists {
+    pub fn builtin() -> Self {
+        Self {
+            answers: Dictionary::builtin_answers(),
+            guesses: Dictionary::builtin_guesses(),
+        }
+    }
+
+    pub fn is_allowed_guess(&self, word: &str) -> bool {
+        self.answers.contains(word) || self.guesses.contains(word)
+    }
+
+    /// Whether `prefix` can still be completed to an allowed guess of
+    /// `word_len` letters.
+    pub fn is_allowed_prefix(&self, prefix: &str, word_len: usize) -> bool {
+        self.answers.has_prefix(prefix, word_len) || self.guesses.has_prefix(prefix, word_len)
+    }
+
+    /// The allowed guesses matching `pattern`, see [`Dictionary::matching`].
+    pub fn matching(&self, pattern: &str) -> Vec<Word> {
+        let mut words = self.answers.matching(pattern);
+        words.extend(self.guesses.matching(pattern));
+        words.sort();
+        words.dedup();
+        words
+    }
+}
+
+impl Default for WordLists {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
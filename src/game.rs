@@ -0,0 +1,295 @@
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::daily;
+use crate::dictionary::WordLists;
+use crate::game_logic::{self, Element, HardModeViolation, Knowledge};
+use crate::rng::SplitMix64;
+use crate::word::Word;
+
+pub const DEFAULT_WORD_LENGTH: usize = 5;
+pub const DEFAULT_MAX_GUESSES: NonZeroUsize = NonZeroUsize::new(6).unwrap();
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, Default)]
+pub enum Difficulty {
+    #[default]
+    #[serde(alias = "normal")]
+    Normal,
+    /// Revealed hints must be used in later guesses.
+    #[serde(alias = "hard")]
+    Hard,
+    /// Hard, and nothing already ruled out may be guessed again.
+    #[serde(alias = "ultra-hard")]
+    UltraHard,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+pub struct GameConfig {
+    pub word_len: usize,
+    /// `None` allows unlimited guesses.
+    pub max_guesses: Option<NonZeroUsize>,
+    pub difficulty: Difficulty,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            word_len: DEFAULT_WORD_LENGTH,
+            max_guesses: Some(DEFAULT_MAX_GUESSES),
+            difficulty: Difficulty::Normal,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+pub enum GameMode {
+    /// `seed` is `None` when the solution was chosen explicitly.
+    Practice {
+        seed: Option<u64>,
+    },
+    Daily {
+        number: u32,
+    },
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum GameState {
+    Running,
+    Won,
+    Lost,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Outcome {
+    Won { attempts: usize },
+    Lost,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum GuessError {
+    GameOver,
+    WrongLength { expected: usize, found: usize },
+    NotInWordList,
+    HardMode(HardModeViolation),
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuessError::GameOver => write!(f, "The game is already over"),
+            GuessError::WrongLength { expected, found } if found < expected => {
+                write!(f, "Not enough letters")
+            }
+            GuessError::WrongLength { .. } => write!(f, "Too many letters"),
+            GuessError::NotInWordList => write!(f, "Not in word list"),
+            GuessError::HardMode(violation) => write!(f, "{}", violation),
+        }
+    }
+}
+
+impl std::error::Error for GuessError {}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    solution: String,
+    guesses: Vec<Vec<Element>>,
+    state: GameState,
+    remaining_attempts: Option<usize>,
+    config: GameConfig,
+    mode: GameMode,
+    words: Arc<WordLists>,
+    /// Play time before `clock_started`; the clock stops when the game ends.
+    elapsed: Duration,
+    clock_started: Option<Instant>,
+}
+
+impl Game {
+    pub fn new(solution: &str, words: Arc<WordLists>, config: GameConfig) -> Self {
+        debug_assert_eq!(solution.len(), config.word_len);
+        Self {
+            solution: solution.to_lowercase(),
+            guesses: vec![],
+            state: GameState::Running,
+            remaining_attempts: config.max_guesses.map(NonZeroUsize::get),
+            config,
+            mode: GameMode::Practice { seed: None },
+            words,
+            elapsed: Duration::ZERO,
+            clock_started: Some(Instant::now()),
+        }
+    }
+
+    /// Rebuilds a game from its solution and guess history. The guesses are
+    /// replayed without validation, so a game saved with different word lists
+    /// still loads.
+    pub(crate) fn restore(
+        solution: &str,
+        guesses: &[String],
+        mode: GameMode,
+        config: GameConfig,
+        elapsed: Duration,
+        words: Arc<WordLists>,
+    ) -> Self {
+        let mut game = Self {
+            mode,
+            ..Self::new(solution, words, config)
+        };
+        for guess in guesses {
+            if game.is_over() {
+                break;
+            }
+            game.record_guess(guess);
+        }
+        game.elapsed = elapsed;
+        if game.is_over() {
+            game.clock_started = None;
+        }
+        game
+    }
+
+    /// A seeded game with a fresh random seed. Panics if the answer list has
+    /// no words of the configured length.
+    pub fn random(words: Arc<WordLists>, config: GameConfig) -> Self {
+        Self::seeded(words, config, rand::random())
+    }
+
+    /// The same seed, word lists and config always give the same solution.
+    /// Panics like [`Game::random`].
+    pub fn seeded(words: Arc<WordLists>, config: GameConfig, seed: u64) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        let solution = rand_from_array(words.answers.words(config.word_len), &mut rng);
+        Self {
+            mode: GameMode::Practice { seed: Some(seed) },
+            ..Self::new(&solution, words, config)
+        }
+    }
+
+    /// The daily puzzle with the given number. Panics like [`Game::random`].
+    pub fn daily(words: Arc<WordLists>, config: GameConfig, number: u32) -> Self {
+        let solution = daily::solution(words.answers.words(config.word_len), number).to_string();
+        Self {
+            mode: GameMode::Daily { number },
+            ..Self::new(&solution, words, config)
+        }
+    }
+
+    /// Scores `guess` against the solution and records it in the history.
+    pub fn submit_guess(&mut self, guess: &str) -> Result<&[Element], GuessError> {
+        if self.is_over() {
+            return Err(GuessError::GameOver);
+        }
+
+        let guess = guess.to_lowercase();
+        if guess.chars().count() != self.config.word_len {
+            return Err(GuessError::WrongLength {
+                expected: self.config.word_len,
+                found: guess.chars().count(),
+            });
+        }
+        if !self.words.is_allowed_guess(&guess) {
+            return Err(GuessError::NotInWordList);
+        }
+        let hints = match self.config.difficulty {
+            Difficulty::Normal => Ok(()),
+            Difficulty::Hard => game_logic::check_hard_mode(&guess, &self.guesses),
+            Difficulty::UltraHard => game_logic::check_ultra_hard_mode(&guess, &self.guesses),
+        };
+        hints.map_err(GuessError::HardMode)?;
+
+        self.record_guess(&guess);
+        if self.is_over() {
+            if let Some(started) = self.clock_started.take() {
+                self.elapsed += started.elapsed();
+            }
+        }
+
+        Ok(self.guesses.last().unwrap())
+    }
+
+    fn record_guess(&mut self, guess: &str) {
+        let elements = game_logic::check_word(guess, &self.solution);
+        self.guesses.push(elements);
+        if let Some(remaining) = self.remaining_attempts.as_mut() {
+            *remaining -= 1;
+        }
+
+        if guess == self.solution {
+            self.state = GameState::Won
+        } else if self.remaining_attempts == Some(0) {
+            self.state = GameState::Lost
+        }
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.state != GameState::Running
+    }
+
+    /// Returns `None` while the game is still running.
+    pub fn outcome(&self) -> Option<Outcome> {
+        match self.state {
+            GameState::Running => None,
+            GameState::Won => Some(Outcome::Won {
+                attempts: self.guesses.len(),
+            }),
+            GameState::Lost => Some(Outcome::Lost),
+        }
+    }
+
+    pub fn solution(&self) -> &str {
+        &self.solution
+    }
+
+    pub fn guesses(&self) -> &[Vec<Element>] {
+        &self.guesses
+    }
+
+    pub fn state(&self) -> GameState {
+        self.state
+    }
+
+    /// `None` if the game allows unlimited guesses.
+    pub fn remaining_attempts(&self) -> Option<usize> {
+        self.remaining_attempts
+    }
+
+    pub fn mode(&self) -> GameMode {
+        self.mode
+    }
+
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    pub fn words(&self) -> &Arc<WordLists> {
+        &self.words
+    }
+
+    /// The possible answers still consistent with every guess so far, in
+    /// alphabetical order.
+    pub fn candidates(&self) -> Vec<Word> {
+        let knowledge = Knowledge::from_history(&self.guesses);
+        let mut candidates: Vec<Word> = self
+            .words
+            .answers
+            .packed(self.config.word_len)
+            .iter()
+            .copied()
+            .filter(|&word| knowledge.matches(word))
+            .collect();
+        candidates.sort();
+        candidates
+    }
+
+    /// Time spent on the game, including time before it was saved.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed + self.clock_started.map_or(Duration::ZERO, |s| s.elapsed())
+    }
+}
+
+pub fn rand_from_array<T: AsRef<str>>(array: &[T], rng: &mut SplitMix64) -> String {
+    array[rng.below(array.len())].as_ref().to_string()
+}
@@ -1,6 +1,9 @@
-use ratatui::style::Color;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
 
-#[derive(PartialEq, Debug, Clone)]
+use crate::word::Word;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum Status {
     Yellow,
     Green,
@@ -8,70 +11,348 @@ pub enum Status {
 }
 
 impl Status {
-    pub fn color(&self) -> Color {
+    /// `G`, `Y` or `.`, as used in plain text patterns.
+    pub fn code(self) -> char {
         match self {
-            Status::Green => Color::Green,
-            Status::Yellow => Color::Yellow,
-            Status::Nothing => Color::White,
+            Status::Green => 'G',
+            Status::Yellow => 'Y',
+            Status::Nothing => '.',
+        }
+    }
+
+    /// Reads `G` and `Y` in either case, and `.`, `-`, `_`, `B` or `X` for a
+    /// letter that isn't in the word.
+    pub fn from_code(c: char) -> Option<Status> {
+        match c.to_ascii_lowercase() {
+            'g' => Some(Status::Green),
+            'y' => Some(Status::Yellow),
+            '.' | '-' | '_' | 'b' | 'x' => Some(Status::Nothing),
+            _ => None,
+        }
+    }
+
+    /// The emoji square for the status. `colorblind` uses orange and blue in
+    /// place of green and yellow.
+    pub fn square(self, colorblind: bool) -> char {
+        match (self, colorblind) {
+            (Status::Green, false) => '🟩',
+            (Status::Yellow, false) => '🟨',
+            (Status::Green, true) => '🟧',
+            (Status::Yellow, true) => '🟦',
+            (Status::Nothing, _) => '⬛',
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct Element {
     pub c: char,
     pub status: Status,
 }
 
-impl Element {
-    pub fn default() -> Self {
-        Self {
-            c: '1',
-            status: Status::Nothing,
+/// Scores `word` against `solution` the way official Wordle does: greens are
+/// assigned first, then yellows from left to right while unmatched copies of
+/// the letter remain in the solution.
+pub fn check_word(word: &str, solution: &str) -> Vec<Element> {
+    match (Word::new(word), Word::new(solution)) {
+        (Some(guess), Some(target)) if guess.len() == target.len() => guess
+            .chars()
+            .zip(guess.score(target).statuses())
+            .map(|(c, status)| Element { c, status })
+            .collect(),
+        _ => check_chars(word, solution),
+    }
+}
+
+/// [`check_word`] on chars with a map of letter counts. Used for words that
+/// don't fit a [`Word`], and as the reference `bench` compares against.
+pub fn check_chars(word: &str, solution: &str) -> Vec<Element> {
+    let word: Vec<char> = word.chars().collect();
+    let solution: Vec<char> = solution.chars().collect();
+
+    let mut remaining: HashMap<char, usize> = HashMap::new();
+    for (i, s) in solution.iter().enumerate() {
+        if word.get(i) != Some(s) {
+            *remaining.entry(*s).or_default() += 1;
         }
     }
+
+    word.iter()
+        .enumerate()
+        .map(|(i, &c)| {
+            let status = if solution.get(i) == Some(&c) {
+                Status::Green
+            } else {
+                match remaining.get_mut(&c) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        Status::Yellow
+                    }
+                    _ => Status::Nothing,
+                }
+            };
+            Element { c, status }
+        })
+        .collect()
 }
 
-pub fn check_word(word: String, solution: String) -> Vec<Element> {
-    let mut return_elements = vec![Element::default(); 5];
-    for (i, c) in word.chars().enumerate() {
-        if c == solution.chars().nth(i).unwrap() {
-            return_elements[i] = Element {
+/// Pairs each letter of `guess` with the status at the same position in
+/// `pattern`, e.g. `("crane", "G..Y.")`. `None` if the lengths differ or the
+/// pattern has an unknown code.
+pub fn elements_from_pattern(guess: &str, pattern: &str) -> Option<Vec<Element>> {
+    if guess.chars().count() != pattern.chars().count() {
+        return None;
+    }
+    guess
+        .chars()
+        .zip(pattern.chars())
+        .map(|(c, code)| {
+            Some(Element {
                 c,
-                status: Status::Green,
-            };
+                status: Status::from_code(code)?,
+            })
+        })
+        .collect()
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum HardModeViolation {
+    /// `position` is zero-based.
+    MissingGreen {
+        position: usize,
+        letter: char,
+    },
+    /// The letter was revealed `count` times.
+    MissingYellow {
+        letter: char,
+        count: usize,
+    },
+    AbsentLetter {
+        letter: char,
+    },
+    /// `position` is zero-based.
+    KnownWrongPosition {
+        position: usize,
+        letter: char,
+    },
+    TooMany {
+        letter: char,
+        max: usize,
+    },
+}
+
+impl fmt::Display for HardModeViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HardModeViolation::MissingGreen { position, letter } => write!(
+                f,
+                "{} letter must be {}",
+                ordinal(position + 1),
+                letter.to_ascii_uppercase()
+            ),
+            HardModeViolation::MissingYellow { letter, count: 1 } => {
+                write!(f, "Guess must contain {}", letter.to_ascii_uppercase())
+            }
+            HardModeViolation::MissingYellow { letter, count } => write!(
+                f,
+                "Guess must contain {} {}'s",
+                count,
+                letter.to_ascii_uppercase()
+            ),
+            HardModeViolation::AbsentLetter { letter } => {
+                write!(f, "Guess must not contain {}", letter.to_ascii_uppercase())
+            }
+            HardModeViolation::KnownWrongPosition { position, letter } => write!(
+                f,
+                "{} letter can't be {}",
+                ordinal(position + 1),
+                letter.to_ascii_uppercase()
+            ),
+            HardModeViolation::TooMany { letter, max } => write!(
+                f,
+                "Guess may contain at most {} {}",
+                max,
+                letter.to_ascii_uppercase()
+            ),
         }
     }
+}
+
+impl std::error::Error for HardModeViolation {}
+
+/// Everything the feedback in a guess history reveals about the solution.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct Knowledge {
+    /// Letters known to be at each position.
+    pub greens: Vec<Option<char>>,
+    /// Letters known not to be at each position.
+    pub excluded: Vec<BTreeSet<char>>,
+    /// Lower bound on how often a letter occurs in the solution.
+    pub min_counts: BTreeMap<char, usize>,
+    /// Upper bound, known once a guess shows a letter grey.
+    pub max_counts: BTreeMap<char, usize>,
+}
 
-    for (i, c) in word.chars().enumerate() {
-        if return_elements[i].status == Status::Green {
-            continue;
+impl Knowledge {
+    pub fn from_history(history: &[Vec<Element>]) -> Self {
+        let mut knowledge = Self::default();
+        for elements in history {
+            knowledge.add_guess(elements);
         }
+        knowledge
+    }
 
-        return_elements[i] = if yellow_or_not(c, &return_elements, &solution) {
-            Element {
-                c,
-                status: Status::Yellow,
+    pub fn add_guess(&mut self, elements: &[Element]) {
+        if self.greens.len() < elements.len() {
+            self.greens.resize(elements.len(), None);
+            self.excluded.resize(elements.len(), BTreeSet::new());
+        }
+
+        let mut shown: BTreeMap<char, usize> = BTreeMap::new();
+        for (position, e) in elements.iter().enumerate() {
+            match e.status {
+                Status::Green => self.greens[position] = Some(e.c),
+                Status::Yellow | Status::Nothing => {
+                    self.excluded[position].insert(e.c);
+                }
             }
-        } else {
-            Element {
-                c,
-                status: Status::Nothing,
+            if e.status != Status::Nothing {
+                *shown.entry(e.c).or_default() += 1;
+            }
+        }
+
+        for (&letter, &count) in &shown {
+            let min = self.min_counts.entry(letter).or_default();
+            *min = (*min).max(count);
+        }
+        for e in elements.iter().filter(|e| e.status == Status::Nothing) {
+            let count = shown.get(&e.c).copied().unwrap_or(0);
+            self.max_counts.insert(e.c, count);
+        }
+    }
+
+    /// The hard mode rules: greens stay in place and every revealed letter
+    /// is used at least as often as it has been shown.
+    pub fn check_hard(&self, guess: &str) -> Result<(), HardModeViolation> {
+        let guess: Vec<char> = guess.chars().collect();
+
+        for (position, green) in self.greens.iter().enumerate() {
+            if let Some(letter) = *green {
+                if guess.get(position) != Some(&letter) {
+                    return Err(HardModeViolation::MissingGreen { position, letter });
+                }
+            }
+        }
+
+        for (&letter, &min) in &self.min_counts {
+            if guess.iter().filter(|&&c| c == letter).count() < min {
+                return Err(HardModeViolation::MissingYellow { letter, count: min });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The hard mode rules plus: no known-absent letters, no letter where it
+    /// was already shown not to be, and no more copies of a letter than the
+    /// solution can have.
+    pub fn check_ultra_hard(&self, guess: &str) -> Result<(), HardModeViolation> {
+        self.check_hard(guess)?;
+
+        for (position, letter) in guess.chars().enumerate() {
+            if self.max_counts.get(&letter) == Some(&0) {
+                return Err(HardModeViolation::AbsentLetter { letter });
+            }
+            if self
+                .excluded
+                .get(position)
+                .is_some_and(|e| e.contains(&letter))
+            {
+                return Err(HardModeViolation::KnownWrongPosition { position, letter });
+            }
+        }
+
+        for (&letter, &max) in &self.max_counts {
+            if guess.chars().filter(|&c| c == letter).count() > max {
+                return Err(HardModeViolation::TooMany { letter, max });
             }
         }
+
+        Ok(())
+    }
+
+    /// Whether `word` could still be the solution.
+    pub fn is_consistent(&self, word: &str) -> bool {
+        match Word::new(word) {
+            Some(word) => self.matches(word),
+            None => self.check_ultra_hard(word).is_ok(),
+        }
     }
 
-    return_elements
+    /// [`Knowledge::is_consistent`] for a packed word, without allocating.
+    pub fn matches(&self, word: Word) -> bool {
+        let greens_match = self.greens.iter().enumerate().all(|(position, green)| {
+            green.is_none_or(|letter| position < word.len() && word.letter(position) == letter)
+        });
+        let counts_match = self
+            .min_counts
+            .iter()
+            .all(|(&c, &min)| word.count(c) >= min)
+            && self
+                .max_counts
+                .iter()
+                .all(|(&c, &max)| word.count(c) <= max);
+        let positions_match = word.chars().enumerate().all(|(position, c)| {
+            !self
+                .excluded
+                .get(position)
+                .is_some_and(|excluded| excluded.contains(&c))
+        });
+        greens_match && counts_match && positions_match
+    }
 }
 
-fn yellow_or_not(c: char, return_elements: &Vec<Element>, solution: &str) -> bool {
-    let mut exists_already = false;
-    for element in return_elements {
-        if element.c == c {
-            exists_already = true;
-            break;
+/// The most informative status each guessed letter has been shown with:
+/// green beats yellow, and yellow beats absent.
+pub fn letter_statuses(history: &[Vec<Element>]) -> HashMap<char, Status> {
+    let rank = |status: Status| match status {
+        Status::Nothing => 0,
+        Status::Yellow => 1,
+        Status::Green => 2,
+    };
+    let mut statuses = HashMap::new();
+    for e in history.iter().flatten() {
+        let best = statuses.entry(e.c).or_insert(e.status);
+        if rank(e.status) > rank(*best) {
+            *best = e.status;
         }
     }
+    statuses
+}
+
+/// Checks that `guess` reuses every hint revealed by `history`: green letters
+/// stay in place and revealed letters appear at least as often as they were
+/// shown green or yellow in any single earlier guess.
+pub fn check_hard_mode(guess: &str, history: &[Vec<Element>]) -> Result<(), HardModeViolation> {
+    Knowledge::from_history(history).check_hard(guess)
+}
+
+/// Like [`check_hard_mode`], but also rejects anything the history already
+/// rules out.
+pub fn check_ultra_hard_mode(
+    guess: &str,
+    history: &[Vec<Element>],
+) -> Result<(), HardModeViolation> {
+    Knowledge::from_history(history).check_ultra_hard(guess)
+}
 
-    solution.contains(c) && !exists_already
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
 }
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::widgets::Widget;
+use serde::{Deserialize, Serialize};
+
+use crate::game_logic::Status;
+use crate::theme::{self, Theme};
+
+pub const QWERTY: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+pub const QWERTZ: [&str; 3] = ["qwertzuiop", "asdfghjkl", "yxcvbnm"];
+pub const AZERTY: [&str; 3] = ["azertyuiop", "qsdfghjklm", "wxcvbn"];
+pub const DVORAK: [&str; 3] = ["pyfgcrl", "aoeuidhtns", "qjkxbmwvz"];
+pub const COLEMAK: [&str; 3] = ["qwfpgjluy", "arstdhneio", "zxcvbkm"];
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    Qwertz,
+    Azerty,
+    Dvorak,
+    Colemak,
+}
+
+impl KeyboardLayout {
+    pub const ALL: [KeyboardLayout; 5] = [
+        KeyboardLayout::Qwerty,
+        KeyboardLayout::Qwertz,
+        KeyboardLayout::Azerty,
+        KeyboardLayout::Dvorak,
+        KeyboardLayout::Colemak,
+    ];
+
+    /// The letter keys, row by row.
+    pub fn rows(self) -> &'static [&'static str] {
+        match self {
+            KeyboardLayout::Qwerty => &QWERTY,
+            KeyboardLayout::Qwertz => &QWERTZ,
+            KeyboardLayout::Azerty => &AZERTY,
+            KeyboardLayout::Dvorak => &DVORAK,
+            KeyboardLayout::Colemak => &COLEMAK,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            KeyboardLayout::Qwerty => "QWERTY",
+            KeyboardLayout::Qwertz => "QWERTZ",
+            KeyboardLayout::Azerty => "AZERTY",
+            KeyboardLayout::Dvorak => "Dvorak",
+            KeyboardLayout::Colemak => "Colemak",
+        }
+    }
+}
+
+/// An on-screen keyboard with every key coloured by what is known about its
+/// letter. Rows are centered and keys lose their gaps when space is short.
+#[derive(Debug, Clone)]
+pub struct Keyboard<'a> {
+    statuses: &'a HashMap<char, Status>,
+    rows: &'a [&'a str],
+    theme: &'a Theme,
+}
+
+impl<'a> Keyboard<'a> {
+    pub fn new(statuses: &'a HashMap<char, Status>) -> Self {
+        Self {
+            statuses,
+            rows: &QWERTY,
+            theme: &theme::DEFAULT,
+        }
+    }
+
+    pub fn rows(mut self, rows: &'a [&'a str]) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    pub fn theme(mut self, theme: &'a Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// The height needed to draw every row.
+    pub fn height(&self) -> u16 {
+        self.rows.len() as u16
+    }
+
+    fn key_style(&self, c: char) -> Style {
+        self.theme.key_style(self.statuses.get(&c).copied())
+    }
+}
+
+impl Widget for Keyboard<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let widest = self
+            .rows
+            .iter()
+            .map(|r| r.chars().count())
+            .max()
+            .unwrap_or(0) as u16;
+        let gap = if (widest * 4).saturating_sub(1) <= area.width {
+            1
+        } else {
+            0
+        };
+
+        for (y, row) in (area.top()..area.bottom()).zip(self.rows) {
+            let keys = row.chars().count() as u16;
+            let width = (keys * (3 + gap)).saturating_sub(gap);
+            let mut x = area.left() + area.width.saturating_sub(width) / 2;
+            for c in row.chars() {
+                if x + 3 > area.right() {
+                    break;
+                }
+                let label = self.theme.label(c, self.statuses.get(&c).copied());
+                buf.set_string(x, y, label, self.key_style(c));
+                x += 3 + gap;
+            }
+        }
+    }
+}
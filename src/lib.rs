@@ -0,0 +1,22 @@
+pub mod animation;
+pub mod board;
+pub mod config;
+pub mod daily;
+pub mod dictionary;
+pub mod game;
+pub mod game_logic;
+mod json_store;
+pub mod keyboard;
+pub mod pattern;
+pub mod rng;
+pub mod save;
+pub mod share;
+pub mod solver;
+pub mod stats;
+pub mod theme;
+pub mod word;
+pub mod word_index;
+pub mod word_list;
+
+pub use dictionary::{Dictionary, DictionaryError, WordLists};
+pub use game::{Difficulty, Game, GameConfig, GameMode, GameState, GuessError, Outcome};
@@ -1,41 +1,506 @@
-use std::io::{Result, stdout};
+mod commands;
 
+use std::fs;
+use std::io::{Result, Write, stdout};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use clap::{Parser, Subcommand};
 use crossterm::{
     event::{self, KeyCode, KeyEventKind},
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     ExecutableCommand,
 };
 use crossterm::event::KeyModifiers;
-use rand::Rng;
-use ratatui::style::Color;
-use ratatui::text::Line;
-use ratatui::widgets::Wrap;
+use ratatui::style::Modifier;
+use ratatui::text::{Line, Span};
+use ratatui::layout::Rect;
+use ratatui::widgets::{Bar, BarChart, BarGroup, Wrap};
+use ratatui::Frame;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
     prelude::{CrosstermBackend, Terminal},
     style::Style,
-    text::Span,
     widgets::Paragraph,
 };
 
-use crate::game_logic::Element;
-use crate::word_list::WORD_LIST;
+use wordle_rust::animation::Animation;
+use wordle_rust::board::Board;
+use wordle_rust::config::{Config, Setting};
+use wordle_rust::daily;
+use wordle_rust::game_logic::{letter_statuses, Status};
+use wordle_rust::keyboard::Keyboard;
+use wordle_rust::save::{SaveStore, SavedGame};
+use wordle_rust::share::{osc52, share_text};
+use wordle_rust::stats::{Stats, StatsStore};
+use wordle_rust::theme::{self, no_color_requested, Theme, Themes};
+use wordle_rust::word::Word;
+use wordle_rust::word_list::{MAX_WORD_LENGTH, MIN_WORD_LENGTH};
+use wordle_rust::{Dictionary, Difficulty, Game, GameConfig, GameMode, GameState, WordLists};
+
+/// Exit status for errors; clap uses the same for bad arguments.
+const EXIT_ERROR: u8 = 2;
+/// Lines the candidate list moves on Page Up and Page Down.
+const CANDIDATES_PAGE: usize = 10;
+
+#[derive(Parser, Debug)]
+#[command(
+    version,
+    about = "Wordle in the terminal",
+    args_conflicts_with_subcommands = true,
+    after_help = "Exit status: 0 on success, 1 if `check` doesn't find the word, 2 on errors."
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    play: PlayArgs,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Play in the terminal (the default when no command is given)
+    Play(PlayArgs),
+    /// Play today's puzzle, the same for everyone on the same day
+    Daily(GameArgs),
+    /// Suggest guesses for a game played elsewhere, from the colours it gave
+    Solve(SolveArgs),
+    /// Print the colours GUESS gets against SOLUTION
+    Score(ScoreArgs),
+    /// Print your statistics
+    Stats(StatsArgs),
+    /// Check whether WORD is in the word lists, or list the words matching a
+    /// pattern like s?a?e
+    Check(CheckArgs),
+    /// Time scoring and filtering guesses
+    Bench(BenchArgs),
+}
+
+#[derive(clap::Args, Debug)]
+struct DictionaryArgs {
+    /// File with the words solutions are drawn from (one per line, or a JSON array)
+    #[arg(long, value_name = "FILE")]
+    answers: Option<PathBuf>,
+
+    /// File with additional words accepted as guesses
+    #[arg(long, value_name = "FILE")]
+    guesses: Option<PathBuf>,
+
+    /// Read settings from FILE instead of the default config.toml
+    #[arg(long, value_name = "FILE")]
+    config: Option<PathBuf>,
+}
+
+#[derive(clap::Args, Debug)]
+struct GameArgs {
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
+
+    /// Number of letters per word [default: 5, or the length of --word]
+    #[arg(short, long, value_parser = parse_word_length)]
+    length: Option<usize>,
+
+    /// Number of guesses allowed per game, or "unlimited" for practice [default: 6]
+    #[arg(short = 'g', long, value_parser = parse_max_guesses)]
+    max_guesses: Option<GuessLimit>,
+
+    /// Revealed hints must be used in every later guess
+    #[arg(long)]
+    hard: bool,
+
+    /// Like --hard, and letters or positions already ruled out may not be guessed again
+    #[arg(long, conflicts_with = "hard")]
+    ultra_hard: bool,
+
+    /// Colour theme: default, high-contrast, monochrome or one from themes.toml
+    #[arg(long, value_name = "NAME")]
+    theme: Option<String>,
+
+    /// Same as --theme high-contrast, with orange and blue instead of green and yellow
+    #[arg(long, conflicts_with = "theme")]
+    colorblind: bool,
+
+    /// Write the result of the last finished game to FILE instead of printing it on exit
+    #[arg(long, value_name = "FILE")]
+    share_file: Option<PathBuf>,
+
+    /// Turn off tile animations
+    #[arg(long)]
+    reduced_motion: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct PlayArgs {
+    #[command(flatten)]
+    game: GameArgs,
+
+    /// Play today's puzzle, same as the `daily` command
+    #[arg(long, conflicts_with_all = ["seed", "word"])]
+    daily: bool,
+
+    /// Pick the solution with this RNG seed, to replay a game
+    #[arg(long, conflicts_with = "word")]
+    seed: Option<u64>,
+
+    /// Use this word as the solution
+    #[arg(long)]
+    word: Option<String>,
+}
+
+#[derive(clap::Args, Debug)]
+struct SolveArgs {
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
+
+    /// Number of letters per word [default: 5]
+    #[arg(short, long, value_parser = parse_word_length)]
+    length: Option<usize>,
+}
+
+#[derive(clap::Args, Debug)]
+struct ScoreArgs {
+    guess: String,
+
+    solution: String,
+
+    /// Print orange and blue squares instead of green and yellow
+    #[arg(long)]
+    colorblind: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct StatsArgs {
+    /// Print the statistics file as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct CheckArgs {
+    /// A word, or a pattern with ? for any letter
+    word: String,
+
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct BenchArgs {
+    #[command(flatten)]
+    dictionary: DictionaryArgs,
+
+    /// Number of letters per word [default: 5]
+    #[arg(short, long, value_parser = parse_word_length)]
+    length: Option<usize>,
+
+    /// Number of solutions to score every guess against
+    #[arg(long, default_value_t = 200)]
+    solutions: usize,
+}
+
+/// The settings in effect, and what the settings screen needs to change them.
+#[derive(Debug)]
+struct Settings {
+    /// The config file with the command line flags applied.
+    current: Config,
+    /// The config file as it is saved. Changes on the settings screen are
+    /// made to both, so flags given for one session aren't saved.
+    file: Config,
+    path: Option<PathBuf>,
+    themes: Themes,
+    /// `NO_COLOR` is set, so the theme is always monochrome.
+    no_color: bool,
+}
+
+impl Settings {
+    fn theme(&self) -> Theme {
+        if self.no_color {
+            return theme::MONOCHROME;
+        }
+        self.themes
+            .get(&self.current.theme)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn step(&mut self, setting: Setting, forward: bool, word_lengths: &[usize]) {
+        let themes: Vec<&str> = self.themes.names().collect();
+        self.current.step(setting, forward, word_lengths, &themes);
+        let current = &self.current;
+        let file = &mut self.file;
+        match setting {
+            Setting::WordLength => file.word_length = current.word_length,
+            Setting::MaxGuesses => file.max_guesses = current.max_guesses,
+            Setting::Difficulty => file.difficulty = current.difficulty,
+            Setting::Theme => file.theme = current.theme.clone(),
+            Setting::KeyboardLayout => file.keyboard_layout = current.keyboard_layout,
+            Setting::Animations => file.animations = current.animations,
+        }
+    }
+
+    fn save(&self) -> std::result::Result<(), String> {
+        let path = self.path.as_ref().ok_or("no config directory")?;
+        self.file.save(path).map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct GuessLimit(Option<NonZeroUsize>);
+
+fn parse_max_guesses(s: &str) -> std::result::Result<GuessLimit, String> {
+    if s.eq_ignore_ascii_case("unlimited") {
+        return Ok(GuessLimit(None));
+    }
+    match s.parse::<NonZeroUsize>() {
+        Ok(n) => Ok(GuessLimit(Some(n))),
+        Err(_) => Err("expected a positive number or \"unlimited\"".to_string()),
+    }
+}
+
+fn parse_word_length(s: &str) -> std::result::Result<usize, String> {
+    let len: usize = s.parse().map_err(|_| format!("`{}` is not a number", s))?;
+    if (MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&len) {
+        Ok(len)
+    } else {
+        Err(format!(
+            "word length must be between {} and {}",
+            MIN_WORD_LENGTH, MAX_WORD_LENGTH
+        ))
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command.unwrap_or(Command::Play(cli.play)) {
+        Command::Play(args) => play(&args),
+        Command::Daily(game) => play(&PlayArgs {
+            game,
+            daily: true,
+            seed: None,
+            word: None,
+        }),
+        Command::Solve(args) => load_dictionary(&args.dictionary, args.length)
+            .and_then(|(words, word_len)| commands::solve(&words, word_len)),
+        Command::Score(args) => commands::score(&args.guess, &args.solution, args.colorblind),
+        Command::Stats(args) => commands::stats(args.json),
+        Command::Check(args) => load_dictionary(&args.dictionary, None)
+            .and_then(|(words, _)| commands::check(&args.word, &words)),
+        Command::Bench(args) => load_dictionary(&args.dictionary, args.length)
+            .and_then(|(words, word_len)| commands::bench(&words, word_len, args.solutions)),
+    };
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+fn play(args: &PlayArgs) -> std::result::Result<ExitCode, String> {
+    let word = args.word.as_ref().map(|w| w.to_lowercase());
+    let settings = load_settings(&args.game, word.as_deref())?;
+    let config = settings.current.game_config();
+
+    let words = load_word_lists(&settings.current)?;
+    let mut game = new_game(args, word.as_deref(), Arc::new(words), config)?;
+
+    // The daily puzzle always continues where it was left, practice games
+    // are only offered when the solution wasn't chosen on the command line.
+    let save_store = SaveStore::open_default();
+    let saved = save_store.as_ref().map(|s| s.load()).unwrap_or_default();
+    let mut screen = Screen::NotStarted;
+    match game.mode() {
+        GameMode::Daily { number } => {
+            if let Some(daily) = saved.daily(number) {
+                game = daily.restore(game.words().clone());
+                screen = Screen::Playing;
+            }
+        }
+        GameMode::Practice { .. } => {
+            if let (Some(practice), None, None) = (saved.practice, &args.seed, &word) {
+                screen = Screen::ResumePrompt(practice);
+            }
+        }
+    }
+
+    let share = run(game, screen, save_store, settings).map_err(|e| e.to_string())?;
+    match (share, &args.game.share_file) {
+        (Some(share), Some(path)) => fs::write(path, share)
+            .map_err(|e| format!("could not write {}: {}", path.display(), e))?,
+        (Some(share), None) => print!("{}", share),
+        (None, _) => {}
+    }
+    Ok(ExitCode::SUCCESS)
+}
 
-mod game_logic;
-mod word_list;
+fn new_game(
+    args: &PlayArgs,
+    word: Option<&str>,
+    words: Arc<WordLists>,
+    config: GameConfig,
+) -> std::result::Result<Game, String> {
+    if let Some(word) = word {
+        if word.len() != config.word_len {
+            return Err(format!(
+                "`{}` has {} letters, but the game uses {}",
+                word,
+                word.len(),
+                config.word_len
+            ));
+        }
+        if !words.is_allowed_guess(word) {
+            return Err(format!("`{}` is not in the dictionary", word));
+        }
+        return Ok(Game::new(word, words, config));
+    }
+
+    if words.answers.words(config.word_len).is_empty() {
+        return Err(format!("no answers with {} letters", config.word_len));
+    }
+    if args.daily {
+        let number = daily::puzzle_number(daily::today())
+            .ok_or_else(|| format!("there is no daily puzzle before {}", daily::epoch()))?;
+        return Ok(Game::daily(words, config, number));
+    }
+    Ok(match args.seed {
+        Some(seed) => Game::seeded(words, config, seed),
+        None => Game::random(words, config),
+    })
+}
+
+fn load_word_lists(config: &Config) -> std::result::Result<WordLists, String> {
+    let load =
+        |path: &PathBuf| Dictionary::load(path).map_err(|e| format!("{}: {}", path.display(), e));
+    let answers = match &config.answers {
+        Some(path) => load(path)?,
+        None => Dictionary::builtin_answers(),
+    };
+    let guesses = match &config.guesses {
+        Some(path) => load(path)?,
+        None => Dictionary::builtin_guesses(),
+    };
+    Ok(WordLists { answers, guesses })
+}
+
+impl DictionaryArgs {
+    /// Reads the config file, returning it with the path it was read from.
+    fn load_config(&self) -> std::result::Result<(Config, Option<PathBuf>), String> {
+        let path = self.config.clone().or_else(Config::default_path);
+        let config = match &path {
+            Some(path) => Config::load(path).map_err(|e| format!("{}: {}", path.display(), e))?,
+            None => Config::default(),
+        };
+        Ok((config, path))
+    }
 
-fn main() -> Result<()> {
+    fn apply(&self, config: &mut Config) {
+        if self.answers.is_some() {
+            config.answers = self.answers.clone();
+        }
+        if self.guesses.is_some() {
+            config.guesses = self.guesses.clone();
+        }
+    }
+}
+
+/// The word lists for the commands that don't start a game, with `length`
+/// replacing the configured word length.
+fn load_dictionary(
+    args: &DictionaryArgs,
+    length: Option<usize>,
+) -> std::result::Result<(WordLists, usize), String> {
+    let (mut config, _) = args.load_config()?;
+    args.apply(&mut config);
+    let words = load_word_lists(&config)?;
+    Ok((words, length.unwrap_or(config.word_length)))
+}
+
+/// Reads the config file and themes, and applies the command line flags.
+fn load_settings(args: &GameArgs, word: Option<&str>) -> std::result::Result<Settings, String> {
+    let (file, path) = args.dictionary.load_config()?;
+    let mut current = file.clone();
+    args.dictionary.apply(&mut current);
+    if let Some(len) = args.length.or(word.map(|w| w.len())) {
+        current.word_length = len;
+    }
+    if let Some(limit) = &args.max_guesses {
+        current.max_guesses = limit.0;
+    }
+    if args.ultra_hard {
+        current.difficulty = Difficulty::UltraHard;
+    } else if args.hard {
+        current.difficulty = Difficulty::Hard;
+    }
+    if let Some(theme) = &args.theme {
+        current.theme = theme.clone();
+    } else if args.colorblind {
+        current.theme = "high-contrast".to_string();
+    }
+    if args.reduced_motion {
+        current.animations = false;
+    }
+
+    let themes = match Themes::default_path() {
+        Some(path) => Themes::load(&path).map_err(|e| format!("{}: {}", path.display(), e))?,
+        None => Themes::default(),
+    };
+    themes.get(&current.theme).map_err(|e| e.to_string())?;
+
+    Ok(Settings {
+        current,
+        file,
+        path,
+        themes,
+        no_color: no_color_requested(),
+    })
+}
+
+/// Returns the share text of the last game finished in this session.
+fn run(
+    mut game: Game,
+    mut screen: Screen,
+    save_store: Option<SaveStore>,
+    mut settings: Settings,
+) -> Result<Option<String>> {
     stdout().execute(EnterAlternateScreen)?;
     enable_raw_mode()?;
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
     terminal.clear()?;
 
     let mut input: String = "".to_string();
-    let mut text_list: Vec<Line> = vec![];
-    let mut game_state = GameState::NotStarted;
-    let mut solution = rand_from_array(&WORD_LIST);
+    let mut show_stats = false;
+    let mut show_settings = false;
+    let mut settings_cursor = 0;
+    let mut show_candidates = false;
+    // Worked out when the panel first needs them after each guess.
+    let mut candidates: Option<Vec<Word>> = None;
+    let mut candidates_scroll = 0;
+    let mut copied = false;
+    let mut animation: Option<Animation> = None;
+    let mut toast: Option<Toast> = None;
+    let colorblind = settings.theme().colorblind;
+    let mut last_share = game.is_over().then(|| share_text(&game, colorblind));
+
+    let stats_store = StatsStore::open_default();
+    let mut stats_status = match &stats_store {
+        Some(store) => store.load().map_err(|e| e.to_string()),
+        None => Err("no data directory to save statistics in".to_string()),
+    };
 
     loop {
+        let now = Instant::now();
+        if animation.is_some_and(|a| a.is_finished(now)) {
+            animation = None;
+        }
+
+        let theme = &settings.theme();
+        let colorblind = theme.colorblind;
         terminal.draw(|frame| {
             let hor = Layout::default()
                 .direction(Direction::Horizontal)
@@ -79,78 +544,230 @@ fn main() -> Result<()> {
             let _area = frame.size();
 
             // Header
+            frame.render_widget(Paragraph::new("").style(theme.header_style()), left_area[0]);
             frame.render_widget(
-                Paragraph::new("").style(Style::new().bg(Color::Green)),
-                left_area[0],
-            );
-            frame.render_widget(
-                Paragraph::new("").style(Style::new().bg(Color::Green)),
+                Paragraph::new("").style(theme.header_style()),
                 right_area[0],
             );
+            let title = match game.mode() {
+                GameMode::Practice { .. } => "WORDLE".to_string(),
+                GameMode::Daily { number } => format!("WORDLE #{}", number),
+            };
             frame.render_widget(
-                Paragraph::new("WORDLE")
-                    .centered()
-                    .style(Style::new().bg(Color::Green)),
+                Paragraph::new(title).centered().style(theme.header_style()),
                 mid_area[0],
             );
 
+            if let Some(toast) = toast.as_ref().filter(|t| t.is_visible(now)) {
+                frame.render_widget(
+                    Paragraph::new(toast.message.as_str())
+                        .centered()
+                        .style(theme.toast_style()),
+                    mid_area[1],
+                );
+            }
+
+            let is_practice = matches!(game.mode(), GameMode::Practice { .. });
+            if show_candidates && is_practice && screen == Screen::Playing {
+                let candidates = candidates.get_or_insert_with(|| game.candidates());
+                let list_height = right_area[2].height as usize;
+                candidates_scroll =
+                    candidates_scroll.min(candidates.len().saturating_sub(list_height));
+                render_candidates(
+                    frame,
+                    left_area[2],
+                    right_area[2],
+                    candidates,
+                    candidates_scroll,
+                    theme,
+                );
+            }
+
             // Body
-            match game_state {
-                GameState::Running => {
-                    frame.render_widget(Paragraph::new(text_list.clone()).centered(), mid_area[2])
+            let show_keyboard = !show_stats
+                && !show_settings
+                && (!game.is_over() || animation.is_some())
+                && screen == Screen::Playing;
+            // A guess being revealed only colours the keyboard once it's done.
+            let shown = animation.map_or(game.guesses().len(), |a| a.row());
+            let statuses = letter_statuses(&game.guesses()[..shown.min(game.guesses().len())]);
+            let keyboard = Keyboard::new(&statuses)
+                .rows(settings.current.keyboard_layout.rows())
+                .theme(theme);
+            let body_area = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Min(0),
+                    Constraint::Length(1),
+                    Constraint::Length(if show_keyboard { keyboard.height() } else { 0 }),
+                ])
+                .split(mid_area[2]);
+            let board_area = body_area[0];
+            if show_keyboard {
+                frame.render_widget(keyboard, body_area[2]);
+            }
+            match (&screen, game.state()) {
+                _ if show_settings => {
+                    render_settings(frame, board_area, &settings, settings_cursor)
                 }
-                GameState::Won => {
+                _ if show_stats => render_stats(frame, board_area, &stats_status, theme),
+                (Screen::Playing, GameState::Won) if animation.is_none() => {
                     let winning_text = format!(
-                        "YOU'VE WON!\nThe word was: {}\nPress Enter to restart.",
-                        solution.to_uppercase()
+                        "YOU'VE WON!\nThe word was: {}\nSolved in {} ({}).\n\n{}\n{}",
+                        game.solution().to_uppercase(),
+                        guess_count_text(game.guesses().len(), game.config()),
+                        elapsed_text(game.elapsed()),
+                        share_text(&game, colorblind),
+                        game_over_hint(copied)
                     );
-                    frame.render_widget(Paragraph::new(winning_text).centered(), mid_area[2]);
+                    frame.render_widget(Paragraph::new(winning_text).centered(), board_area);
                 }
-                GameState::Lost => {
+                (Screen::Playing, GameState::Lost) if animation.is_none() => {
                     let losing_text = format!(
-                        "YOU'VE LOST!\nThe word was: {}\nPress Enter to restart.",
-                        solution.to_uppercase()
+                        "YOU'VE LOST!\nThe word was: {}\nYou used all {} guesses ({}).\n\n{}\n{}",
+                        game.solution().to_uppercase(),
+                        game.guesses().len(),
+                        elapsed_text(game.elapsed()),
+                        share_text(&game, colorblind),
+                        game_over_hint(copied)
                     );
-                    frame.render_widget(Paragraph::new(losing_text).centered(), mid_area[2]);
-                }
-                GameState::NotStarted => {
-                    let not_started_text =
-                        "Type 5-character-words and press ENTER to submit them.\n\n\
-                        You have 6 tries to guess the correct word.\n\n\
-                        Green means the letter is at the right place.\n\n\
-                        Yellow means the letter is in the word but not in the right place.\n\n\
-                        Press ESC to exit.";
+                    frame.render_widget(Paragraph::new(losing_text).centered(), board_area);
+                }
+                (Screen::Playing, state) => {
+                    let word_len = game.config().word_len;
+                    let mut board = Board::new(game.guesses(), word_len)
+                        .theme(theme)
+                        .max_guesses(game.config().max_guesses.map(NonZeroUsize::get));
+                    if state == GameState::Running {
+                        board = board
+                            .input(&input)
+                            .input_valid(game.words().is_allowed_prefix(&input, word_len));
+                    }
+                    if let Some(animation) = animation {
+                        board = board.animation(animation, now);
+                    }
+                    frame.render_widget(board, board_area)
+                }
+                (Screen::NotStarted, _) => {
+                    let not_started_text = intro_text(game.config(), theme);
                     frame.render_widget(
                         Paragraph::new(not_started_text)
                             .wrap(Wrap::default())
                             .centered(),
-                        mid_area[2],
+                        board_area,
                     );
                 }
-                GameState::WrongWord => {
-                    let wrong_word_text = "The Word was not valid¸\n Please enter a valid word";
-                    frame.render_widget(Paragraph::new(wrong_word_text).centered(), mid_area[2]);
+                (Screen::ResumePrompt(saved), _) => {
+                    let resume_text = format!(
+                        "You have an unfinished game ({}, {} played).\n\n\
+                        Resume it? (Y/N)",
+                        guess_count_text(saved.guesses.len(), &saved.config),
+                        elapsed_text(saved.elapsed)
+                    );
+                    frame.render_widget(
+                        Paragraph::new(resume_text).wrap(Wrap::default()).centered(),
+                        board_area,
+                    );
                 }
             };
             // Footer
-            let input_prompt: String = format!(
-                "Input: {}{}",
-                input.to_uppercase().clone(),
-                "_".repeat(5 - input.len())
-            );
-            frame.render_widget(Paragraph::new("").centered(), left_area[3]);
-            frame.render_widget(Paragraph::new(input_prompt).centered(), mid_area[3]);
-            frame.render_widget(Paragraph::new("").centered(), right_area[3]);
+            let seed = match game.mode() {
+                GameMode::Practice { seed: Some(seed) } => format!("Seed: {}", seed),
+                _ => "".to_string(),
+            };
+            frame.render_widget(Paragraph::new(seed).centered(), left_area[3]);
+            let tries = match game.remaining_attempts() {
+                Some(remaining) => format!("Tries left: {}", remaining),
+                None => format!("Tries: {}", game.guesses().len()),
+            };
+            frame.render_widget(Paragraph::new(tries).centered(), right_area[3]);
         })?;
 
         if event::poll(std::time::Duration::from_millis(16))? {
             if let event::Event::Key(key) = event::read()? {
+                // Ctrl+C quits from every screen.
+                if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+                    break;
+                }
+                let toggles_settings = key.code == KeyCode::F(2) || key.code == KeyCode::Esc;
+                if show_settings && toggles_settings {
+                    show_settings = false;
+                    toast = Some(match settings.save() {
+                        Ok(()) => Toast::new("Settings saved".to_string(), now),
+                        Err(e) => Toast::new(format!("Settings not saved: {}", e), now),
+                    });
+                    continue;
+                }
+                if show_settings {
+                    let word_lengths: Vec<usize> = game.words().answers.lengths().collect();
+                    let setting = Setting::ALL[settings_cursor];
+                    match key.code {
+                        KeyCode::Up => settings_cursor = settings_cursor.saturating_sub(1),
+                        KeyCode::Down => {
+                            settings_cursor = (settings_cursor + 1).min(Setting::ALL.len() - 1)
+                        }
+                        KeyCode::Left => settings.step(setting, false, &word_lengths),
+                        KeyCode::Right | KeyCode::Enter | KeyCode::Char(' ') => {
+                            settings.step(setting, true, &word_lengths)
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+
                 if key.kind == KeyEventKind::Press && key.code == KeyCode::Esc {
                     break;
                 }
 
-                if game_state == GameState::NotStarted {
-                    game_state = GameState::Running
+                if let Screen::ResumePrompt(saved) = &screen {
+                    match key.code {
+                        KeyCode::Char('y' | 'Y') => {
+                            game = saved.restore(game.words().clone());
+                            screen = Screen::Playing;
+                            candidates = None;
+                        }
+                        KeyCode::Char('n' | 'N') => screen = Screen::NotStarted,
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                if key.code == KeyCode::Tab {
+                    show_stats = !show_stats;
+                    continue;
+                }
+                if key.code == KeyCode::F(2) {
+                    show_settings = true;
+                    show_stats = false;
+                    continue;
+                }
+                if key.code == KeyCode::F(3) {
+                    show_candidates = !show_candidates;
+                    continue;
+                }
+                if show_stats {
+                    continue;
+                }
+                if show_candidates {
+                    match key.code {
+                        KeyCode::Up => candidates_scroll = candidates_scroll.saturating_sub(1),
+                        KeyCode::Down => candidates_scroll += 1,
+                        KeyCode::PageUp => {
+                            candidates_scroll = candidates_scroll.saturating_sub(CANDIDATES_PAGE)
+                        }
+                        KeyCode::PageDown => candidates_scroll += CANDIDATES_PAGE,
+                        _ => {}
+                    }
+                }
+
+                if screen == Screen::NotStarted {
+                    screen = Screen::Playing
+                }
+
+                // Any key skips an animation. Once the game is over the key
+                // does nothing else, so Enter doesn't restart by accident.
+                if animation.take().is_some() && game.is_over() {
+                    continue;
                 }
 
                 match key.code {
@@ -159,35 +776,63 @@ fn main() -> Result<()> {
                             break;
                         }
 
-                        if input.len() < 5 && c.is_ascii() && c.is_alphabetic() {
+                        if game.is_over() && c.eq_ignore_ascii_case(&'c') {
+                            stdout().write_all(osc52(&share_text(&game, colorblind)).as_bytes())?;
+                            stdout().flush()?;
+                            copied = true;
+                            continue;
+                        }
+
+                        let word_len = game.config().word_len;
+                        if input.len() < word_len && c.is_ascii() && c.is_alphabetic() {
                             input.push(c.to_ascii_lowercase())
                         }
                     }
                     KeyCode::Enter => {
-                        if game_state == GameState::Won || game_state == GameState::Lost {
-                            game_state = GameState::Running;
-                            text_list = vec![];
+                        if game.is_over() {
+                            screen = Screen::Playing;
                             input = "".to_string();
-                            solution = rand_from_array(&WORD_LIST)
-                        }
-                        else if WORD_LIST.contains(&input.as_str()) {
-                            if game_state == GameState::WrongWord {
-                                game_state = GameState::Running;
+                            copied = false;
+                            toast = None;
+                            let config = settings.current.game_config();
+                            game = Game::random(game.words().clone(), config);
+                            candidates = None;
+                            candidates_scroll = 0;
+                        } else {
+                            let row = game.guesses().len();
+                            let word_len = game.config().word_len;
+                            match game.submit_guess(&input) {
+                                Ok(_) => {
+                                    input = "".to_string();
+                                    toast = None;
+                                    candidates = None;
+                                    candidates_scroll = 0;
+                                    if settings.current.animations {
+                                        let won = game.state() == GameState::Won;
+                                        animation =
+                                            Some(Animation::reveal(row, word_len, won, now));
+                                    }
+                                }
+                                Err(e) => {
+                                    toast = Some(Toast::new(e.to_string(), now));
+                                    if settings.current.animations {
+                                        animation = Some(Animation::shake(row, word_len, now));
+                                    }
+                                }
                             }
-                            if input.len() < 5 { continue; }
 
-                            let elements = game_logic::check_word(
-                                input.to_string(),
-                                solution.to_string(),
-                            );
-                            text_list.push(list_to_span(&elements));
-
-                            if input == solution { game_state = GameState::Won }
-                            if text_list.len() > 5 { game_state = GameState::Lost }
-                            input = "".to_string();
-                        } else {
-                            game_state = GameState::WrongWord;
-                            input = "".to_string();
+                            if let (Some(outcome), Some(store)) = (game.outcome(), &stats_store) {
+                                stats_status = store.record(outcome).map_err(|e| e.to_string());
+                            }
+                            if game.is_over() {
+                                last_share = Some(share_text(&game, colorblind));
+                            }
+                            // Saved right away so quitting can't undo a daily guess.
+                            if let (GameMode::Daily { .. }, Some(store)) =
+                                (game.mode(), &save_store)
+                            {
+                                let _ = store.store(&game);
+                            }
                         }
                     }
                     KeyCode::Backspace => {
@@ -203,34 +848,226 @@ fn main() -> Result<()> {
 
     stdout().execute(LeaveAlternateScreen)?;
     disable_raw_mode()?;
-    Ok(())
+
+    // Quitting at the resume prompt leaves the saved game alone.
+    let resume_pending = matches!(screen, Screen::ResumePrompt(_));
+    if let (false, Some(store)) = (resume_pending, &save_store) {
+        if let Err(e) = store.store(&game) {
+            eprintln!(
+                "warning: could not save the game to {}: {}",
+                store.path().display(),
+                e
+            );
+        }
+    }
+    Ok(last_share)
 }
 
 #[derive(PartialEq, Debug, Clone)]
-enum GameState {
+enum Screen {
     NotStarted,
-    Running,
-    WrongWord,
-    Won,
-    Lost,
+    Playing,
+    /// An unfinished practice game was found on launch.
+    ResumePrompt(SavedGame),
+}
+
+/// A short message shown above the board, e.g. why a guess was rejected.
+struct Toast {
+    message: String,
+    shown: Instant,
+}
+
+impl Toast {
+    const DURATION: Duration = Duration::from_millis(2000);
+
+    fn new(message: String, now: Instant) -> Self {
+        Self {
+            message,
+            shown: now,
+        }
+    }
+
+    fn is_visible(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.shown) < Self::DURATION
+    }
 }
 
-fn list_to_span(elements: &[Element]) -> Line<'static> {
-    let mut styled_chars: Vec<Span> = elements
+fn render_stats(
+    frame: &mut Frame,
+    area: Rect,
+    stats: &std::result::Result<Stats, String>,
+    theme: &Theme,
+) {
+    let stats = match stats {
+        Ok(stats) => stats,
+        Err(e) => {
+            let text = format!("Statistics are unavailable:\n{}", e);
+            frame.render_widget(Paragraph::new(text).wrap(Wrap::default()).centered(), area);
+            return;
+        }
+    };
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(7), Constraint::Min(1)])
+        .split(area);
+
+    let summary = format!(
+        "STATISTICS\n\n\
+        Played: {}   Win %: {}\n\
+        Current streak: {}   Max streak: {}\n\n\
+        GUESS DISTRIBUTION",
+        stats.played,
+        stats.win_rate(),
+        stats.current_streak,
+        stats.max_streak
+    );
+    frame.render_widget(Paragraph::new(summary).centered(), layout[0]);
+
+    let bars: Vec<Bar> = stats
+        .guess_distribution
+        .iter()
+        .enumerate()
+        .map(|(i, &count)| {
+            Bar::default()
+                .label(Line::from((i + 1).to_string()))
+                .value(count as u64)
+                .text_value(count.to_string())
+                .style(Style::new().fg(theme.accent))
+                .value_style(theme.header_style())
+        })
+        .collect();
+    frame.render_widget(
+        BarChart::default()
+            .direction(Direction::Horizontal)
+            .bar_width(1)
+            .bar_gap(0)
+            .data(BarGroup::default().bars(&bars)),
+        layout[1],
+    );
+}
+
+/// How many answers are still possible, and the list of them. `scroll` is
+/// the index of the first word shown.
+fn render_candidates(
+    frame: &mut Frame,
+    count_area: Rect,
+    list_area: Rect,
+    candidates: &[Word],
+    scroll: usize,
+    theme: &Theme,
+) {
+    let count = match candidates.len() {
+        1 => "1 word left".to_string(),
+        n => format!("{} words left", n),
+    };
+    let lines = vec![
+        Line::from(count),
+        Line::default(),
+        Line::styled("↑↓ scroll, F3 hides", Style::new().fg(theme.muted)),
+    ];
+    frame.render_widget(Paragraph::new(lines).centered(), count_area);
+
+    let words: Vec<Line> = candidates
+        .iter()
+        .skip(scroll)
+        .take(list_area.height as usize)
+        .map(|w| Line::from(w.to_string().to_uppercase()))
+        .collect();
+    frame.render_widget(Paragraph::new(words).centered(), list_area);
+}
+
+fn render_settings(frame: &mut Frame, area: Rect, settings: &Settings, cursor: usize) {
+    let mut lines: Vec<Line> = Setting::ALL
         .iter()
-        .map(|e| {
-            Span::styled(
-                e.c.to_string().to_uppercase(),
-                Style::default().fg(e.status.color()),
-            )
+        .enumerate()
+        .map(|(i, &setting)| {
+            let text = format!(
+                "{:<12} ◀ {:^14} ▶",
+                setting.label(),
+                settings.current.value_text(setting)
+            );
+            if i == cursor {
+                Line::styled(text, Style::new().add_modifier(Modifier::REVERSED))
+            } else {
+                Line::from(text)
+            }
         })
         .collect();
-    styled_chars.push(Span::styled("\n".to_string(), Style::default()));
 
-    Line::from(styled_chars)
+    let path_text = |path: &Option<PathBuf>| {
+        path.as_ref()
+            .map_or("built-in".to_string(), |p| p.display().to_string())
+    };
+    lines.push(Line::default());
+    lines.push(Line::from(format!(
+        "Answers: {}",
+        path_text(&settings.current.answers)
+    )));
+    lines.push(Line::from(format!(
+        "Guesses: {}",
+        path_text(&settings.current.guesses)
+    )));
+    if settings.no_color {
+        lines.push(Line::from("NO_COLOR is set, so the theme is monochrome."));
+    }
+    lines.push(Line::default());
+    lines.push(Line::from("Changes to the game apply from the next one."));
+    lines.push(Line::from("←→ change, F2 or ESC saves."));
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap::default()).centered(), area);
+}
+
+fn intro_text(config: &GameConfig, theme: &Theme) -> Vec<Line<'static>> {
+    let tries = config
+        .max_guesses
+        .map_or("unlimited".to_string(), |n| n.to_string());
+    let example = |status| Span::styled(theme.label('a', Some(status)), theme.hint_style(status));
+    vec![
+        Line::from(format!(
+            "Type {}-character-words and press ENTER to submit them.",
+            config.word_len
+        )),
+        Line::default(),
+        Line::from(format!(
+            "You have {} tries to guess the correct word.",
+            tries
+        )),
+        Line::default(),
+        Line::from(vec![
+            example(Status::Green),
+            Span::raw(" means the letter is at the right place."),
+        ]),
+        Line::default(),
+        Line::from(vec![
+            example(Status::Yellow),
+            Span::raw(" means the letter is in the word but not in the right place."),
+        ]),
+        Line::default(),
+        Line::from("Press TAB to show your statistics, F2 to change settings."),
+        Line::default(),
+        Line::from("In practice games, F3 lists the words that are still possible."),
+        Line::default(),
+        Line::from("Press ESC to exit."),
+    ]
+}
+
+fn guess_count_text(guesses: usize, config: &GameConfig) -> String {
+    match config.max_guesses {
+        Some(max) => format!("{}/{} guesses", guesses, max),
+        None if guesses == 1 => "1 guess".to_string(),
+        None => format!("{} guesses", guesses),
+    }
+}
+
+fn game_over_hint(copied: bool) -> &'static str {
+    if copied {
+        "Copied to the clipboard. Press Enter to restart."
+    } else {
+        "Press C to copy the result, Enter to restart."
+    }
 }
 
-fn rand_from_array(array: &[&str]) -> String {
-    let random = rand::thread_rng().gen_range(0..array.len());
-    array[random].to_string()
+fn elapsed_text(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    format!("{}:{:02}", secs / 60, secs % 60)
 }
@@ -0,0 +1,296 @@
+use std::fmt;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use memmap2::Mmap;
+use rayon::prelude::*;
+
+use crate::game_logic::{Element, Status};
+use crate::word::{Feedback, Word};
+
+const MAGIC: &[u8; 4] = b"WRPM";
+/// Bumped whenever the file layout or the pattern encoding changes.
+const VERSION: u32 = 1;
+/// Magic, version, guess count, answer count and word list fingerprint.
+const HEADER_LEN: usize = 4 + 4 + 4 + 4 + 8;
+
+/// The feedback for a guess of up to five letters as a base-3 number, first
+/// letter most significant, with grey = 0, yellow = 1 and green = 2.
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Debug, Clone, Copy)]
+pub struct Pattern(u8);
+
+impl Pattern {
+    /// The longest word a pattern can describe.
+    pub const MAX_LEN: usize = 5;
+    /// The number of different patterns, 3^5.
+    pub const COUNT: usize = 243;
+
+    /// Scores `guess` against `solution` with [`Word::score`]. `None` if the
+    /// words differ in length or are longer than [`Pattern::MAX_LEN`].
+    pub fn score(guess: Word, solution: Word) -> Option<Pattern> {
+        if guess.len() != solution.len() {
+            return None;
+        }
+        Self::from_feedback(guess.score(solution))
+    }
+
+    pub fn from_feedback(feedback: Feedback) -> Option<Pattern> {
+        (feedback.len() <= Self::MAX_LEN).then(|| Pattern(feedback.value() as u8))
+    }
+
+    /// `None` for more than [`Pattern::MAX_LEN`] statuses.
+    pub fn from_statuses<I: IntoIterator<Item = Status>>(statuses: I) -> Option<Pattern> {
+        let mut value: u8 = 0;
+        for (i, status) in statuses.into_iter().enumerate() {
+            if i == Self::MAX_LEN {
+                return None;
+            }
+            value = value * 3
+                + match status {
+                    Status::Nothing => 0,
+                    Status::Yellow => 1,
+                    Status::Green => 2,
+                };
+        }
+        Some(Pattern(value))
+    }
+
+    pub fn from_elements(elements: &[Element]) -> Option<Pattern> {
+        Self::from_statuses(elements.iter().map(|e| e.status))
+    }
+
+    /// The statuses of a word of `len` letters.
+    pub fn statuses(self, len: usize) -> Vec<Status> {
+        let mut value = self.0;
+        let mut statuses = vec![Status::Nothing; len];
+        for status in statuses.iter_mut().rev() {
+            *status = match value % 3 {
+                0 => Status::Nothing,
+                1 => Status::Yellow,
+                _ => Status::Green,
+            };
+            value /= 3;
+        }
+        statuses
+    }
+
+    pub fn value(self) -> u8 {
+        self.0
+    }
+}
+
+#[derive(Debug)]
+pub enum PatternError {
+    Io(io::Error),
+    /// The words aren't all the same length of at most [`Pattern::MAX_LEN`].
+    UnsupportedWord(String),
+    /// The cache file is from another version or other word lists.
+    InvalidCache(String),
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::Io(e) => write!(f, "could not access pattern cache: {}", e),
+            PatternError::UnsupportedWord(word) => write!(
+                f,
+                "\"{}\" must have at most {} letters, as many as the other words",
+                word,
+                Pattern::MAX_LEN
+            ),
+            PatternError::InvalidCache(reason) => write!(f, "invalid pattern cache: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+impl From<io::Error> for PatternError {
+    fn from(e: io::Error) -> Self {
+        PatternError::Io(e)
+    }
+}
+
+#[derive(Debug)]
+enum Data {
+    Built(Vec<u8>),
+    /// A cache file, patterns starting after the header.
+    Mapped(Mmap),
+}
+
+/// The pattern of every guess against every answer, one byte each, row by
+/// row. Cache files hold a header with the format version and a fingerprint
+/// of the word lists, so a file for other lists is never used.
+#[derive(Debug)]
+pub struct PatternMatrix {
+    guesses: usize,
+    answers: usize,
+    data: Data,
+}
+
+impl PatternMatrix {
+    /// `<cache dir>/wordle-rust/patterns-<word_len>.bin`, if the platform has
+    /// a cache directory.
+    pub fn default_path(word_len: usize) -> Option<PathBuf> {
+        dirs::cache_dir().map(|dir| {
+            dir.join("wordle-rust")
+                .join(format!("patterns-{}.bin", word_len))
+        })
+    }
+
+    /// Scores every pair on all cores.
+    pub fn build(guesses: &[Word], answers: &[Word]) -> Result<Self, PatternError> {
+        let len = guesses.first().map_or(0, |g| g.len());
+        if let Some(word) = guesses
+            .iter()
+            .chain(answers)
+            .find(|w| w.len() != len || w.len() > Pattern::MAX_LEN)
+        {
+            return Err(PatternError::UnsupportedWord(word.to_string()));
+        }
+
+        let mut data = vec![0; guesses.len() * answers.len()];
+        if !answers.is_empty() {
+            data.par_chunks_mut(answers.len())
+                .zip(guesses.par_iter())
+                .for_each(|(row, &guess)| {
+                    for (cell, &answer) in row.iter_mut().zip(answers) {
+                        *cell = guess.score(answer).value() as u8;
+                    }
+                });
+        }
+        Ok(Self {
+            guesses: guesses.len(),
+            answers: answers.len(),
+            data: Data::Built(data),
+        })
+    }
+
+    /// Maps a cache file written by [`PatternMatrix::save`] for the same
+    /// word lists.
+    pub fn load(path: &Path, guesses: &[Word], answers: &[Word]) -> Result<Self, PatternError> {
+        let file = File::open(path)?;
+        // SAFETY: cache files are only ever replaced by renaming a finished
+        // file over them, and every writer uses its own temporary file, so a
+        // mapped file is never written to.
+        let map = unsafe { Mmap::map(&file)? };
+        let invalid = |reason: &str| Err(PatternError::InvalidCache(reason.to_string()));
+
+        if map.len() < HEADER_LEN || &map[..4] != MAGIC {
+            return invalid("not a pattern cache");
+        }
+        let u32_at = |i: usize| u32::from_le_bytes(map[i..i + 4].try_into().unwrap());
+        if u32_at(4) != VERSION {
+            return invalid("written by another version");
+        }
+        let fingerprint = u64::from_le_bytes(map[16..24].try_into().unwrap());
+        if u32_at(8) as usize != guesses.len()
+            || u32_at(12) as usize != answers.len()
+            || fingerprint != word_list_fingerprint(guesses, answers)
+        {
+            return invalid("built for other word lists");
+        }
+        if map.len() != HEADER_LEN + guesses.len() * answers.len() {
+            return invalid("truncated");
+        }
+
+        Ok(Self {
+            guesses: guesses.len(),
+            answers: answers.len(),
+            data: Data::Mapped(map),
+        })
+    }
+
+    /// Writes the matrix with a header for the lists it was built from.
+    pub fn save(
+        &self,
+        path: &Path,
+        guesses: &[Word],
+        answers: &[Word],
+    ) -> Result<(), PatternError> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.patterns().len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        bytes.extend_from_slice(&(self.guesses as u32).to_le_bytes());
+        bytes.extend_from_slice(&(self.answers as u32).to_le_bytes());
+        bytes.extend_from_slice(&word_list_fingerprint(guesses, answers).to_le_bytes());
+        bytes.extend_from_slice(self.patterns());
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Unique per process and call, so concurrent builds never share a
+        // file that one of them renames into place.
+        static SAVES: AtomicU32 = AtomicU32::new(0);
+        let tmp = path.with_extension(format!(
+            "bin.{}-{}.tmp",
+            std::process::id(),
+            SAVES.fetch_add(1, Ordering::Relaxed)
+        ));
+        let written = fs::write(&tmp, bytes).and_then(|()| fs::rename(&tmp, path));
+        if written.is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+        Ok(written?)
+    }
+
+    /// Loads the cache at `path`, or builds the matrix and writes the cache
+    /// if it is missing or out of date.
+    pub fn load_or_build(
+        path: &Path,
+        guesses: &[Word],
+        answers: &[Word],
+    ) -> Result<Self, PatternError> {
+        if let Ok(matrix) = Self::load(path, guesses, answers) {
+            return Ok(matrix);
+        }
+        let matrix = Self::build(guesses, answers)?;
+        // A cache that can't be written only costs time on the next start.
+        let _ = matrix.save(path, guesses, answers);
+        Ok(matrix)
+    }
+
+    pub fn guess_count(&self) -> usize {
+        self.guesses
+    }
+
+    pub fn answer_count(&self) -> usize {
+        self.answers
+    }
+
+    /// The pattern of guess number `guess` against answer number `answer`.
+    pub fn get(&self, guess: usize, answer: usize) -> Pattern {
+        Pattern(self.patterns()[guess * self.answers + answer])
+    }
+
+    /// Whether the matrix was read from a cache file.
+    pub fn is_mapped(&self) -> bool {
+        matches!(self.data, Data::Mapped(_))
+    }
+
+    fn patterns(&self) -> &[u8] {
+        match &self.data {
+            Data::Built(data) => data,
+            Data::Mapped(map) => &map[HEADER_LEN..],
+        }
+    }
+}
+
+/// FNV-1a over both lists, which stays the same across Rust releases.
+fn word_list_fingerprint(guesses: &[Word], answers: &[Word]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let words = guesses
+        .iter()
+        .map(Some)
+        .chain([None])
+        .chain(answers.iter().map(Some));
+    for word in words {
+        for c in word.into_iter().flat_map(|w| w.chars()).chain(['\n']) {
+            hash ^= c as u64;
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
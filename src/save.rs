@@ -0,0 +1,139 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::dictionary::WordLists;
+use crate::game::{Game, GameConfig, GameMode};
+use crate::json_store;
+use crate::word_list::{MAX_WORD_LENGTH, MIN_WORD_LENGTH};
+
+/// Everything needed to continue a game later.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct SavedGame {
+    pub solution: String,
+    pub guesses: Vec<String>,
+    pub mode: GameMode,
+    pub config: GameConfig,
+    pub elapsed: Duration,
+}
+
+impl SavedGame {
+    pub fn from_game(game: &Game) -> Self {
+        Self {
+            solution: game.solution().to_string(),
+            guesses: game
+                .guesses()
+                .iter()
+                .map(|g| g.iter().map(|e| e.c).collect())
+                .collect(),
+            mode: game.mode(),
+            config: *game.config(),
+            elapsed: game.elapsed(),
+        }
+    }
+
+    /// Whether the game can be restored: the solution is a lowercase word of
+    /// the configured length, which is one the game supports, and every guess
+    /// has that length too.
+    pub fn is_valid(&self) -> bool {
+        let word_len = self.config.word_len;
+        (MIN_WORD_LENGTH..=MAX_WORD_LENGTH).contains(&word_len)
+            && self.solution.len() == word_len
+            && self.solution.chars().all(|c| c.is_ascii_lowercase())
+            && self.guesses.iter().all(|g| g.chars().count() == word_len)
+    }
+
+    pub fn restore(&self, words: Arc<WordLists>) -> Game {
+        Game::restore(
+            &self.solution,
+            &self.guesses,
+            self.mode,
+            self.config,
+            self.elapsed,
+            words,
+        )
+    }
+}
+
+/// One slot for an unfinished practice game and one for the daily puzzle.
+/// The daily slot keeps finished games too, so the puzzle can't be replayed.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+#[serde(default)]
+pub struct SaveFile {
+    pub practice: Option<SavedGame>,
+    pub daily: Option<SavedGame>,
+}
+
+impl SaveFile {
+    /// Stores `game` in the slot for its mode. Practice games that are
+    /// finished or haven't been started clear their slot instead.
+    pub fn store(&mut self, game: &Game) {
+        match game.mode() {
+            GameMode::Daily { .. } => self.daily = Some(SavedGame::from_game(game)),
+            GameMode::Practice { .. } if game.is_over() || game.guesses().is_empty() => {
+                self.practice = None
+            }
+            GameMode::Practice { .. } => self.practice = Some(SavedGame::from_game(game)),
+        }
+    }
+
+    /// The saved daily game, if it is for puzzle `number`.
+    pub fn daily(&self, number: u32) -> Option<&SavedGame> {
+        self.daily
+            .as_ref()
+            .filter(|saved| saved.mode == GameMode::Daily { number })
+    }
+}
+
+/// Saved games persisted as JSON. Writes hold an exclusive lock on a sidecar
+/// lock file like [`StatsStore`](crate::stats::StatsStore), so concurrent
+/// instances don't overwrite each other's slots.
+#[derive(Debug, Clone)]
+pub struct SaveStore {
+    path: PathBuf,
+}
+
+impl SaveStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// `<data dir>/wordle-rust/save.json`, if the platform has a data directory.
+    pub fn open_default() -> Option<Self> {
+        dirs::data_dir().map(|dir| Self::new(dir.join("wordle-rust").join("save.json")))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// A missing or unreadable save file counts as empty, and a saved game
+    /// that isn't [valid](SavedGame::is_valid) as no save.
+    pub fn load(&self) -> SaveFile {
+        let file: SaveFile = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+        SaveFile {
+            practice: file.practice.filter(SavedGame::is_valid),
+            daily: file.daily.filter(SavedGame::is_valid),
+        }
+    }
+
+    pub fn save(&self, file: &SaveFile) -> io::Result<()> {
+        let _lock = json_store::lock(&self.path)?;
+        json_store::write(&self.path, file)
+    }
+
+    /// Stores `game` in its slot, keeping the other slot as it is on disk.
+    pub fn store(&self, game: &Game) -> io::Result<()> {
+        let _lock = json_store::lock(&self.path)?;
+        let mut file = self.load();
+        file.store(game);
+        json_store::write(&self.path, &file)
+    }
+}
@@ -0,0 +1,51 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+use crate::game::{Difficulty, Game, GameMode, Outcome};
+
+/// A spoiler-free summary of a finished game, e.g.
+///
+/// ```text
+/// Wordle-rust 123 4/6*
+///
+/// ⬛🟨⬛⬛⬛
+/// ⬛🟩⬛🟨⬛
+/// 🟩🟩⬛🟩⬛
+/// 🟩🟩🟩🟩🟩
+/// ```
+///
+/// `*` marks hard mode and `**` ultra hard mode. `colorblind` uses orange and
+/// blue squares in place of green and yellow.
+pub fn share_text(game: &Game, colorblind: bool) -> String {
+    let name = match game.mode() {
+        GameMode::Daily { number } => format!("Wordle-rust {}", number),
+        GameMode::Practice { seed: Some(seed) } => format!("Wordle-rust seed {}", seed),
+        GameMode::Practice { seed: None } => "Wordle-rust practice".to_string(),
+    };
+    let attempts = match game.outcome() {
+        Some(Outcome::Won { attempts }) => attempts.to_string(),
+        _ => "X".to_string(),
+    };
+    let limit = match game.config().max_guesses {
+        Some(max) => max.to_string(),
+        None => "∞".to_string(),
+    };
+    let difficulty = match game.config().difficulty {
+        Difficulty::Normal => "",
+        Difficulty::Hard => "*",
+        Difficulty::UltraHard => "**",
+    };
+
+    let mut text = format!("{} {}/{}{}\n", name, attempts, limit, difficulty);
+    for guess in game.guesses() {
+        text.push('\n');
+        text.extend(guess.iter().map(|e| e.status.square(colorblind)));
+    }
+    text
+}
+
+/// The OSC 52 escape sequence that asks the terminal to put `text` on the
+/// system clipboard. Not every terminal supports it.
+pub fn osc52(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", STANDARD.encode(text))
+}
@@ -0,0 +1,163 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
+
+use rayon::prelude::*;
+
+use crate::dictionary::WordLists;
+use crate::pattern::{Pattern, PatternMatrix};
+use crate::word::{Feedback, Word};
+
+/// A guess and the information its feedback is expected to give.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Suggestion {
+    pub word: Word,
+    /// Shannon entropy of the feedback over the remaining candidates, in bits.
+    pub entropy: f64,
+    /// The guess could still be the solution.
+    pub is_candidate: bool,
+}
+
+/// Tracks the solutions that are still possible after each guess and ranks
+/// the next guesses by how evenly their feedback splits those solutions.
+#[derive(Debug)]
+pub struct Solver {
+    /// Sorted, so a guess can be found by binary search.
+    guesses: Vec<Word>,
+    answers: Vec<Word>,
+    /// Indices into `answers`.
+    candidates: Vec<usize>,
+    matrix: Option<PatternMatrix>,
+}
+
+impl Solver {
+    /// `guesses` are the words that may be suggested, `answers` the possible
+    /// solutions.
+    pub fn new(mut guesses: Vec<Word>, answers: Vec<Word>) -> Self {
+        guesses.sort();
+        guesses.dedup();
+        Self {
+            guesses,
+            candidates: (0..answers.len()).collect(),
+            answers,
+            matrix: None,
+        }
+    }
+
+    /// Suggests any allowed guess of `word_len` letters, with the answer list
+    /// as the possible solutions.
+    pub fn from_word_lists(words: &WordLists, word_len: usize) -> Self {
+        let answers = words.answers.packed(word_len).to_vec();
+        let mut guesses = answers.clone();
+        guesses.extend_from_slice(words.guesses.packed(word_len));
+        Self::new(guesses, answers)
+    }
+
+    /// Looks patterns up in `matrix` instead of scoring them. The matrix must
+    /// be built from [`Solver::guesses`] and [`Solver::answers`].
+    pub fn with_matrix(mut self, matrix: PatternMatrix) -> Self {
+        assert_eq!(matrix.guess_count(), self.guesses.len());
+        assert_eq!(matrix.answer_count(), self.answers.len());
+        self.matrix = Some(matrix);
+        self
+    }
+
+    pub fn guesses(&self) -> &[Word] {
+        &self.guesses
+    }
+
+    pub fn answers(&self) -> &[Word] {
+        &self.answers
+    }
+
+    pub fn candidates(&self) -> Vec<Word> {
+        self.candidates.iter().map(|&i| self.answers[i]).collect()
+    }
+
+    /// Keeps the candidates that would have given `feedback` for `guess`.
+    pub fn apply(&mut self, guess: Word, feedback: Feedback) {
+        self.candidates = self.filter(guess, feedback);
+    }
+
+    /// Like [`Solver::apply`], but if no candidate would have given
+    /// `feedback` the candidates are left alone and `false` is returned.
+    pub fn try_apply(&mut self, guess: Word, feedback: Feedback) -> bool {
+        let candidates = self.filter(guess, feedback);
+        if candidates.is_empty() {
+            return false;
+        }
+        self.candidates = candidates;
+        true
+    }
+
+    /// The expected information in bits from guessing `guess`.
+    pub fn entropy(&self, guess: Word) -> f64 {
+        let index = self.guesses.binary_search(&guess).ok();
+        let total = self.candidates.len() as f64;
+        let entropy = |count: usize| {
+            let p = count as f64 / total;
+            -p * p.log2()
+        };
+
+        if guess.len() <= Pattern::MAX_LEN {
+            let mut counts = [0; Pattern::COUNT];
+            for &answer in &self.candidates {
+                counts[self.pattern(guess, index, answer) as usize] += 1;
+            }
+            counts.into_iter().filter(|&c| c > 0).map(entropy).sum()
+        } else {
+            // Summed in pattern order like the array, so every word length is
+            // ranked the same way.
+            let mut counts: BTreeMap<u32, usize> = BTreeMap::new();
+            for &answer in &self.candidates {
+                *counts
+                    .entry(self.pattern(guess, index, answer))
+                    .or_default() += 1;
+            }
+            counts.into_values().map(entropy).sum()
+        }
+    }
+
+    /// The `n` guesses with the highest entropy. Ties go to guesses that could
+    /// be the solution, then alphabetical order.
+    pub fn best_guesses(&self, n: usize) -> Vec<Suggestion> {
+        let candidates: HashSet<Word> = self.candidates().into_iter().collect();
+
+        let mut suggestions: Vec<Suggestion> = self
+            .guesses
+            .par_iter()
+            .map(|&guess| Suggestion {
+                word: guess,
+                entropy: self.entropy(guess),
+                is_candidate: candidates.contains(&guess),
+            })
+            .collect();
+        suggestions.sort_by(|a, b| {
+            b.entropy
+                .partial_cmp(&a.entropy)
+                .unwrap_or(Ordering::Equal)
+                .then(b.is_candidate.cmp(&a.is_candidate))
+                .then(a.word.cmp(&b.word))
+        });
+        suggestions.truncate(n);
+        suggestions
+    }
+
+    /// The candidates that would have given `feedback` for `guess`.
+    fn filter(&self, guess: Word, feedback: Feedback) -> Vec<usize> {
+        let index = self.guesses.binary_search(&guess).ok();
+        let expected = feedback.value();
+        self.candidates
+            .iter()
+            .copied()
+            .filter(|&answer| self.pattern(guess, index, answer) == expected)
+            .collect()
+    }
+
+    /// The pattern of `guess` against an answer, as [`Feedback::value`].
+    fn pattern(&self, guess: Word, index: Option<usize>, answer: usize) -> u32 {
+        match (&self.matrix, index) {
+            (Some(matrix), Some(index)) => matrix.get(index, answer).value() as u32,
+            _ => guess.score(self.answers[answer]).value(),
+        }
+    }
+}
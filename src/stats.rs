@@ -0,0 +1,95 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::Outcome;
+use crate::json_store;
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Stats {
+    pub played: u32,
+    pub wins: u32,
+    pub current_streak: u32,
+    pub max_streak: u32,
+    /// `guess_distribution[i]` counts the wins that took `i + 1` guesses.
+    pub guess_distribution: Vec<u32>,
+}
+
+impl Stats {
+    pub fn record(&mut self, outcome: Outcome) {
+        self.played += 1;
+        match outcome {
+            Outcome::Won { attempts } => {
+                self.wins += 1;
+                self.current_streak += 1;
+                self.max_streak = self.max_streak.max(self.current_streak);
+                if self.guess_distribution.len() < attempts {
+                    self.guess_distribution.resize(attempts, 0);
+                }
+                self.guess_distribution[attempts - 1] += 1;
+            }
+            Outcome::Lost => self.current_streak = 0,
+        }
+    }
+
+    /// Percentage of games won, 0 if none were played.
+    pub fn win_rate(&self) -> u32 {
+        (self.wins * 100).checked_div(self.played).unwrap_or(0)
+    }
+}
+
+/// Statistics persisted as JSON. Updates hold an exclusive lock on a sidecar
+/// lock file and replace the data file atomically, so concurrent instances
+/// don't lose games. A file that can't be parsed is moved aside and the
+/// statistics start over.
+#[derive(Debug, Clone)]
+pub struct StatsStore {
+    path: PathBuf,
+}
+
+impl StatsStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// `<data dir>/wordle-rust/stats.json`, if the platform has a data directory.
+    pub fn open_default() -> Option<Self> {
+        dirs::data_dir().map(|dir| Self::new(dir.join("wordle-rust").join("stats.json")))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn load(&self) -> io::Result<Stats> {
+        let _lock = json_store::lock(&self.path)?;
+        self.read()
+    }
+
+    /// Adds a finished game and returns the updated statistics.
+    pub fn record(&self, outcome: Outcome) -> io::Result<Stats> {
+        let _lock = json_store::lock(&self.path)?;
+        let mut stats = self.read()?;
+        stats.record(outcome);
+        json_store::write(&self.path, &stats)?;
+        Ok(stats)
+    }
+
+    fn read(&self) -> io::Result<Stats> {
+        let text = match fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Stats::default()),
+            Err(e) => return Err(e),
+        };
+        match serde_json::from_str(&text) {
+            Ok(stats) => Ok(stats),
+            Err(_) => {
+                fs::rename(&self.path, self.path.with_extension("json.corrupt"))?;
+                Ok(Stats::default())
+            }
+        }
+    }
+}
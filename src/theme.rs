@@ -0,0 +1,261 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+
+use crate::game_logic::Status;
+
+/// The colours every widget draws with. User themes only need to list the
+/// fields they change; the rest come from [`DEFAULT`].
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[serde(default, deny_unknown_fields)]
+pub struct Theme {
+    /// Tile background for a letter in the right place.
+    pub correct: Color,
+    /// Tile background for a letter that is elsewhere in the word.
+    pub present: Color,
+    /// Tile background for a letter not in the word.
+    pub absent: Color,
+    /// Letters on correct and present tiles.
+    pub on_hint: Color,
+    /// Letters on absent tiles.
+    pub on_absent: Color,
+    pub text: Color,
+    /// Typed letters that can't start any allowed word.
+    pub invalid: Color,
+    /// Empty tiles and keys that haven't been guessed.
+    pub muted: Color,
+    /// Header and statistics chart.
+    pub accent: Color,
+    pub on_accent: Color,
+    /// Draw correct letters as `[A]` and present ones as `(A)`, so hints
+    /// don't depend on colour.
+    pub markers: bool,
+    /// Share results with orange and blue squares.
+    pub colorblind: bool,
+}
+
+pub const DEFAULT: Theme = Theme {
+    correct: Color::Green,
+    present: Color::Yellow,
+    absent: Color::DarkGray,
+    on_hint: Color::Black,
+    on_absent: Color::White,
+    text: Color::White,
+    invalid: Color::Red,
+    muted: Color::DarkGray,
+    accent: Color::Green,
+    on_accent: Color::Black,
+    markers: false,
+    colorblind: false,
+};
+
+/// Orange and blue, which stay apart for the common kinds of colour blindness.
+pub const HIGH_CONTRAST: Theme = Theme {
+    correct: Color::Rgb(245, 121, 58),
+    present: Color::Rgb(133, 192, 249),
+    accent: Color::Rgb(245, 121, 58),
+    colorblind: true,
+    ..DEFAULT
+};
+
+/// No colours at all; hints are shown with glyphs and text attributes.
+pub const MONOCHROME: Theme = Theme {
+    correct: Color::Reset,
+    present: Color::Reset,
+    absent: Color::Reset,
+    on_hint: Color::Reset,
+    on_absent: Color::Reset,
+    text: Color::Reset,
+    invalid: Color::Reset,
+    muted: Color::Reset,
+    accent: Color::Reset,
+    on_accent: Color::Reset,
+    markers: true,
+    colorblind: false,
+};
+
+pub const BUILTIN: [(&str, Theme); 3] = [
+    ("default", DEFAULT),
+    ("high-contrast", HIGH_CONTRAST),
+    ("monochrome", MONOCHROME),
+];
+
+impl Default for Theme {
+    fn default() -> Self {
+        DEFAULT
+    }
+}
+
+impl Theme {
+    /// A scored tile or key.
+    pub fn hint_style(&self, status: Status) -> Style {
+        match status {
+            Status::Green => Style::new()
+                .fg(self.on_hint)
+                .bg(self.correct)
+                .add_modifier(Modifier::BOLD),
+            Status::Yellow => Style::new()
+                .fg(self.on_hint)
+                .bg(self.present)
+                .add_modifier(Modifier::BOLD),
+            Status::Nothing if self.markers => Style::new().add_modifier(Modifier::DIM),
+            Status::Nothing => Style::new().fg(self.on_absent).bg(self.absent),
+        }
+    }
+
+    /// A letter typed but not yet submitted.
+    pub fn typed_style(&self) -> Style {
+        Style::new().fg(self.text).add_modifier(Modifier::BOLD)
+    }
+
+    /// Typed letters that no allowed word starts with. Underlined as well,
+    /// so it shows without colour.
+    pub fn invalid_style(&self) -> Style {
+        self.typed_style()
+            .fg(self.invalid)
+            .add_modifier(Modifier::UNDERLINED)
+    }
+
+    pub fn empty_style(&self) -> Style {
+        Style::new().fg(self.muted)
+    }
+
+    /// A keyboard key, `None` if the letter hasn't been guessed.
+    pub fn key_style(&self, status: Option<Status>) -> Style {
+        match status {
+            Some(Status::Nothing) => Style::new()
+                .fg(self.muted)
+                .add_modifier(Modifier::CROSSED_OUT | Modifier::DIM),
+            Some(status) => self.hint_style(status),
+            None if self.markers => Style::new(),
+            None => Style::new().fg(self.text).bg(self.muted),
+        }
+    }
+
+    pub fn header_style(&self) -> Style {
+        Style::new().fg(self.on_accent).bg(self.accent)
+    }
+
+    pub fn toast_style(&self) -> Style {
+        Style::new().add_modifier(Modifier::REVERSED)
+    }
+
+    /// A letter padded to three cells, with markers if the theme uses them.
+    pub fn label(&self, c: char, status: Option<Status>) -> String {
+        let c = c.to_ascii_uppercase();
+        match status {
+            Some(Status::Green) if self.markers => format!("[{}]", c),
+            Some(Status::Yellow) if self.markers => format!("({})", c),
+            _ => format!(" {} ", c),
+        }
+    }
+}
+
+/// Whether the user asked for no colours, see <https://no-color.org>.
+pub fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+#[derive(Debug)]
+pub enum ThemeError {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    Unknown {
+        name: String,
+        available: Vec<String>,
+    },
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::Io(e) => write!(f, "could not read themes: {}", e),
+            ThemeError::Toml(e) => write!(f, "invalid themes file: {}", e),
+            ThemeError::Unknown { name, available } => write!(
+                f,
+                "unknown theme \"{}\", available: {}",
+                name,
+                available.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+impl From<io::Error> for ThemeError {
+    fn from(e: io::Error) -> Self {
+        ThemeError::Io(e)
+    }
+}
+
+impl From<toml::de::Error> for ThemeError {
+    fn from(e: toml::de::Error) -> Self {
+        ThemeError::Toml(e)
+    }
+}
+
+/// The built-in themes plus the user's own. User themes are TOML tables keyed
+/// by name and may replace a built-in theme:
+///
+/// ```toml
+/// [solarized]
+/// correct = "#859900"
+/// present = "#b58900"
+/// ```
+#[derive(Debug, Clone)]
+pub struct Themes {
+    themes: BTreeMap<String, Theme>,
+}
+
+impl Default for Themes {
+    fn default() -> Self {
+        Self {
+            themes: BUILTIN
+                .into_iter()
+                .map(|(name, theme)| (name.to_string(), theme))
+                .collect(),
+        }
+    }
+}
+
+impl Themes {
+    /// `<config dir>/wordle-rust/themes.toml`, if the platform has a config
+    /// directory.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("wordle-rust").join("themes.toml"))
+    }
+
+    pub fn from_toml(text: &str) -> Result<Self, ThemeError> {
+        let user: BTreeMap<String, Theme> = toml::from_str(text)?;
+        let mut themes = Self::default();
+        themes.themes.extend(user);
+        Ok(themes)
+    }
+
+    /// Loads the user themes at `path`. A missing file just gives the
+    /// built-in themes.
+    pub fn load(path: &Path) -> Result<Self, ThemeError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::from_toml(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.themes.keys().map(String::as_str)
+    }
+
+    pub fn get(&self, name: &str) -> Result<&Theme, ThemeError> {
+        self.themes.get(name).ok_or_else(|| ThemeError::Unknown {
+            name: name.to_string(),
+            available: self.names().map(str::to_string).collect(),
+        })
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt;
+
+use crate::game_logic::Status;
+use crate::word_list::MAX_WORD_LENGTH;
+
+const BITS_PER_LETTER: usize = 5;
+const LETTER_MASK: u64 = (1 << BITS_PER_LETTER) - 1;
+const BITS_PER_COUNT: usize = 4;
+
+/// A word of lowercase ASCII letters packed into integers. Letters take five
+/// bits each, first letter highest, so words order alphabetically. Letter
+/// counts are kept four bits per letter and presence one bit per letter, so
+/// scoring and filtering never allocate or scan.
+#[derive(PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub struct Word {
+    letters: u64,
+    len: u8,
+    counts: u128,
+    presence: u32,
+}
+
+impl Word {
+    pub const MAX_LEN: usize = MAX_WORD_LENGTH;
+
+    /// `None` unless `word` is 1 to [`Word::MAX_LEN`] lowercase ASCII letters.
+    pub fn new(word: &str) -> Option<Word> {
+        let bytes = word.as_bytes();
+        if bytes.is_empty() || bytes.len() > Self::MAX_LEN {
+            return None;
+        }
+        let mut packed = Word {
+            letters: 0,
+            len: bytes.len() as u8,
+            counts: 0,
+            presence: 0,
+        };
+        for (i, &b) in bytes.iter().enumerate() {
+            if !b.is_ascii_lowercase() {
+                return None;
+            }
+            let letter = b - b'a';
+            packed.letters |= (letter as u64 + 1) << Self::shift(i);
+            packed.counts += 1 << (letter as usize * BITS_PER_COUNT);
+            packed.presence |= 1 << letter;
+        }
+        Some(packed)
+    }
+
+    fn shift(position: usize) -> usize {
+        (Self::MAX_LEN - 1 - position) * BITS_PER_LETTER
+    }
+
+    pub fn len(self) -> usize {
+        self.len as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.len == 0
+    }
+
+    /// The letter at `position` as 0 for `a` to 25 for `z`.
+    fn index(self, position: usize) -> u8 {
+        ((self.letters >> Self::shift(position)) & LETTER_MASK) as u8 - 1
+    }
+
+    pub fn letter(self, position: usize) -> char {
+        (b'a' + self.index(position)) as char
+    }
+
+    pub fn chars(self) -> impl Iterator<Item = char> {
+        (0..self.len()).map(move |i| self.letter(i))
+    }
+
+    /// How often `c` occurs.
+    pub fn count(self, c: char) -> usize {
+        match letter_index(c) {
+            Some(letter) => ((self.counts >> (letter * BITS_PER_COUNT)) & 0xf) as usize,
+            None => 0,
+        }
+    }
+
+    pub fn contains(self, c: char) -> bool {
+        letter_index(c).is_some_and(|letter| self.presence & (1 << letter) != 0)
+    }
+
+    /// Whether the first letters are those of `prefix`.
+    pub fn starts_with(self, prefix: Word) -> bool {
+        let rest = Self::shift(prefix.len() - 1);
+        prefix.len() <= self.len() && self.letters >> rest == prefix.letters >> rest
+    }
+
+    /// One bit per letter that occurs, `a` lowest.
+    pub fn presence(self) -> u32 {
+        self.presence
+    }
+
+    /// Scores this guess against `solution` the way official Wordle does,
+    /// like [`check_word`](crate::game_logic::check_word). Only the first
+    /// `solution.len()` letters count if the lengths differ.
+    pub fn score(self, solution: Word) -> Feedback {
+        let len = self.len().min(solution.len());
+        let mut feedback = Feedback {
+            bits: 0,
+            len: len as u8,
+        };
+        let mut remaining = solution.counts;
+        let same = self.letters ^ solution.letters;
+        for i in 0..len {
+            if (same >> Self::shift(i)) & LETTER_MASK == 0 {
+                feedback.bits |= Feedback::GREEN << (2 * i);
+                remaining -= 1 << (self.index(i) as usize * BITS_PER_COUNT);
+            }
+        }
+
+        // Letters the solution doesn't have stay grey without a count lookup.
+        for i in 0..len {
+            let letter = self.index(i);
+            if feedback.bits & (Feedback::GREEN << (2 * i)) != 0
+                || solution.presence & (1 << letter) == 0
+            {
+                continue;
+            }
+            let shift = letter as usize * BITS_PER_COUNT;
+            if (remaining >> shift) & 0xf > 0 {
+                remaining -= 1 << shift;
+                feedback.bits |= Feedback::YELLOW << (2 * i);
+            }
+        }
+        feedback
+    }
+}
+
+fn letter_index(c: char) -> Option<usize> {
+    c.is_ascii_lowercase().then(|| (c as u8 - b'a') as usize)
+}
+
+impl fmt::Display for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.chars().try_for_each(|c| write!(f, "{}", c))
+    }
+}
+
+impl fmt::Debug for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Word(\"{}\")", self)
+    }
+}
+
+/// The statuses of a scored guess, two bits per letter.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Feedback {
+    bits: u32,
+    len: u8,
+}
+
+impl Feedback {
+    /// `None` for more than [`Word::MAX_LEN`] statuses.
+    pub fn from_statuses<I: IntoIterator<Item = Status>>(statuses: I) -> Option<Feedback> {
+        let mut feedback = Feedback { bits: 0, len: 0 };
+        for status in statuses {
+            if feedback.len() == Word::MAX_LEN {
+                return None;
+            }
+            feedback.bits |= Self::code(status) << (2 * feedback.len);
+            feedback.len += 1;
+        }
+        Some(feedback)
+    }
+
+    const YELLOW: u32 = 1;
+    const GREEN: u32 = 2;
+
+    fn code(status: Status) -> u32 {
+        match status {
+            Status::Nothing => 0,
+            Status::Yellow => Self::YELLOW,
+            Status::Green => Self::GREEN,
+        }
+    }
+
+    pub fn len(self) -> usize {
+        self.len as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.len == 0
+    }
+
+    pub fn status(self, position: usize) -> Status {
+        match (self.bits >> (2 * position)) & 0b11 {
+            0 => Status::Nothing,
+            1 => Status::Yellow,
+            _ => Status::Green,
+        }
+    }
+
+    pub fn statuses(self) -> impl Iterator<Item = Status> {
+        (0..self.len()).map(move |i| self.status(i))
+    }
+
+    pub fn is_win(self) -> bool {
+        self.statuses().all(|s| s == Status::Green)
+    }
+
+    /// The statuses as a base-3 number, first letter most significant, with
+    /// grey = 0, yellow = 1 and green = 2.
+    pub fn value(self) -> u32 {
+        (0..self.len()).fold(0, |value, i| value * 3 + ((self.bits >> (2 * i)) & 0b11))
+    }
+}
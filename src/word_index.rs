@@ -0,0 +1,103 @@
+use crate::word::Word;
+
+/// Prefixes of up to this many letters are kept in the bitset.
+const BITSET_PREFIX: usize = 3;
+/// Letters plus "no letter", the digits of a bitset prefix.
+const RADIX: usize = 27;
+
+/// A sorted set of words for binary search, with a bitset of every prefix of
+/// up to three letters so that most misses are rejected in O(1).
+#[derive(Debug, Clone, Default)]
+pub struct WordIndex {
+    words: Vec<Word>,
+    prefixes: Vec<u64>,
+}
+
+impl WordIndex {
+    pub fn new<I: IntoIterator<Item = Word>>(words: I) -> Self {
+        let mut words: Vec<Word> = words.into_iter().collect();
+        words.sort();
+        words.dedup();
+
+        let mut prefixes = vec![0u64; RADIX.pow(BITSET_PREFIX as u32).div_ceil(64)];
+        for word in &words {
+            for len in 1..=word.len().min(BITSET_PREFIX) {
+                let bit = prefix_bit(word.chars().take(len));
+                prefixes[bit / 64] |= 1 << (bit % 64);
+            }
+        }
+        Self { words, prefixes }
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Every word in alphabetical order.
+    pub fn words(&self) -> &[Word] {
+        &self.words
+    }
+
+    pub fn contains(&self, word: Word) -> bool {
+        self.may_have_prefix(word.chars()) && self.words.binary_search(&word).is_ok()
+    }
+
+    /// Whether some word starts with `prefix`. The empty prefix starts every
+    /// word.
+    pub fn has_prefix(&self, prefix: &str) -> bool {
+        if prefix.is_empty() {
+            return !self.words.is_empty();
+        }
+        !self.with_prefix(prefix).is_empty()
+    }
+
+    /// The words that start with `prefix`, in alphabetical order.
+    pub fn with_prefix(&self, prefix: &str) -> &[Word] {
+        if prefix.is_empty() {
+            return &self.words;
+        }
+        let Some(prefix) = Word::new(prefix) else {
+            return &[];
+        };
+        if !self.may_have_prefix(prefix.chars()) {
+            return &[];
+        }
+        let start = self.words.partition_point(|w| *w < prefix);
+        let len = self.words[start..].partition_point(|w| w.starts_with(prefix));
+        &self.words[start..start + len]
+    }
+
+    /// The words matching `pattern`, where `?` stands for any one letter,
+    /// e.g. `s?a?e`.
+    pub fn matching(&self, pattern: &str) -> Vec<Word> {
+        let fixed: String = pattern.chars().take_while(|&c| c != '?').collect();
+        let letters: Vec<Option<char>> = pattern.chars().map(|c| (c != '?').then_some(c)).collect();
+        self.with_prefix(&fixed)
+            .iter()
+            .filter(|w| {
+                w.len() == letters.len()
+                    && w.chars()
+                        .zip(&letters)
+                        .all(|(c, letter)| letter.is_none_or(|l| l == c))
+            })
+            .copied()
+            .collect()
+    }
+
+    /// `false` if the bitset rules out any word starting with `letters`.
+    fn may_have_prefix<I: Iterator<Item = char>>(&self, letters: I) -> bool {
+        let bit = prefix_bit(letters.take(BITSET_PREFIX));
+        self.prefixes[bit / 64] & (1 << (bit % 64)) != 0
+    }
+}
+
+/// Up to three lowercase letters as a base-27 number, 0 standing for a
+/// missing letter.
+fn prefix_bit<I: Iterator<Item = char>>(letters: I) -> usize {
+    let mut digits = letters.map(|c| (c as u8 - b'a') as usize + 1);
+    (0..BITSET_PREFIX).fold(0, |bit, _| bit * RADIX + digits.next().unwrap_or(0))
+}
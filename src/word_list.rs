@@ -1,4 +1,283 @@
-pub const WORD_LIST: [&str; 14855] = [
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::dictionary::WordLists;
+
+pub const MIN_WORD_LENGTH: usize = 4;
+pub const MAX_WORD_LENGTH: usize = 11;
+
+macro_rules! word_files {
+    ($len:literal) => {
+        (
+            $len,
+            include_str!(concat!("../words/answers_", $len, ".txt")),
+            include_str!(concat!("../words/guesses_", $len, ".txt")),
+        )
+    };
+}
+
+// Five-letter words live in the arrays below, every other length in `words/`.
+static WORD_FILES: [(usize, &str, &str); 7] = [
+    word_files!(4),
+    word_files!(6),
+    word_files!(7),
+    word_files!(8),
+    word_files!(9),
+    word_files!(10),
+    word_files!(11),
+];
+
+type Bucket = (Vec<&'static str>, Vec<&'static str>);
+
+fn buckets() -> &'static HashMap<usize, Bucket> {
+    static BUCKETS: OnceLock<HashMap<usize, Bucket>> = OnceLock::new();
+    BUCKETS.get_or_init(|| {
+        let mut buckets = HashMap::new();
+        buckets.insert(5, (ANSWERS.to_vec(), ALLOWED_GUESSES.to_vec()));
+        for (len, answers, guesses) in WORD_FILES {
+            buckets.insert(len, (answers.lines().collect(), guesses.lines().collect()));
+        }
+        buckets
+    })
+}
+
+pub fn answers(word_len: usize) -> &'static [&'static str] {
+    buckets().get(&word_len).map_or(&[], |b| &b.0)
+}
+
+pub fn allowed_guesses(word_len: usize) -> &'static [&'static str] {
+    buckets().get(&word_len).map_or(&[], |b| &b.1)
+}
+
+/// The built-in lists, indexed for lookups.
+fn word_lists() -> &'static WordLists {
+    static WORD_LISTS: OnceLock<WordLists> = OnceLock::new();
+    WORD_LISTS.get_or_init(WordLists::builtin)
+}
+
+pub fn is_answer(word: &str) -> bool {
+    word_lists().answers.contains(word)
+}
+
+pub fn is_allowed_guess(word: &str) -> bool {
+    word_lists().is_allowed_guess(word)
+}
+
+pub static ANSWERS: [&str; 2104] = [
+    "aback", "abaft", "abbey", "abbot", "abhor", "abide", "abled", "abode", "abort", "about",
+    "above", "abuse", "acorn", "acrid", "actor", "acute", "adage", "adapt", "adept", "admin",
+    "admit", "adobe", "adopt", "adore", "adorn", "adult", "affix", "afire", "afoot", "afoul",
+    "after", "again", "agape", "agate", "agent", "agile", "aging", "aglow", "agony", "agora",
+    "agree", "ahead", "aider", "aimer", "aisle", "alarm", "album", "alert", "alias", "alibi",
+    "alien", "align", "alike", "alive", "alley", "allow", "alloy", "aloft", "aloha", "alone",
+    "along", "aloof", "alpha", "altar", "alter", "amass", "amaze", "amber", "amble", "amend",
+    "amiss", "amity", "among", "ample", "amply", "amuck", "angel", "anger", "angle", "angry",
+    "angst", "anime", "ankle", "annex", "annoy", "annul", "anode", "antic", "anvil", "aorta",
+    "apart", "apnea", "apple", "apron", "aptly", "arbor", "ardor", "arena", "argon", "argue",
+    "arise", "armed", "armor", "aroma", "arose", "array", "arrow", "arson", "artsy", "ascot",
+    "ashen", "aside", "askew", "assay", "asset", "atoll", "atone", "attic", "audio", "audit",
+    "augur", "avail", "avert", "avoid", "awake", "aware", "awful", "axiom", "azure", "bacon",
+    "badge", "badly", "bagel", "baggy", "baker", "baler", "balmy", "banal", "banjo", "barge",
+    "baron", "basal", "basic", "basil", "basin", "baste", "batch", "bathe", "baton", "batty",
+    "bawdy", "bayou", "beach", "beady", "beard", "beast", "beefy", "befit", "began", "begat",
+    "beget", "begin", "begun", "beige", "being", "belch", "belie", "belly", "below", "bench",
+    "beret", "berry", "berth", "beset", "bevel", "bible", "bicep", "bight", "bigot", "bilge",
+    "binge", "bingo", "biome", "birch", "birth", "bison", "black", "blade", "blame", "bland",
+    "blank", "blare", "blast", "blaze", "bleak", "bleat", "bleed", "blend", "bless", "blimp",
+    "blind", "blink", "bliss", "blitz", "bloat", "block", "bloke", "blond", "blood", "bloom",
+    "blown", "bluer", "bluff", "blunt", "blurb", "blurt", "blush", "board", "bobby", "bogus",
+    "boney", "bonus", "booby", "boost", "booth", "booty", "booze", "boozy", "borax", "bored",
+    "borne", "bosom", "bossy", "botch", "bough", "boule", "bound", "bowel", "boxer", "brace",
+    "braid", "brain", "brake", "brand", "brash", "brass", "brave", "brawl", "brawn", "bread",
+    "briar", "bribe", "brick", "bride", "brief", "brine", "bring", "brink", "briny", "brisk",
+    "broad", "broke", "brood", "brook", "broom", "broth", "brown", "brunt", "brush", "brute",
+    "buddy", "budge", "buggy", "bugle", "build", "built", "bulge", "bulky", "bully", "bumpy",
+    "bunch", "bunny", "burly", "burnt", "burro", "burst", "bushy", "butch", "butte", "buxom",
+    "buyer", "cabal", "cabin", "cable", "cacao", "cache", "cadet", "cagey", "camel", "cameo",
+    "canal", "candy", "canny", "canoe", "caper", "carat", "cargo", "carol", "carry", "carve",
+    "caste", "catch", "catty", "caulk", "cause", "cease", "cedar", "cello", "chafe", "chaff",
+    "chain", "chair", "chalk", "champ", "chant", "chaos", "chard", "charm", "chart", "chase",
+    "chasm", "cheap", "check", "cheek", "cheer", "chess", "chest", "chewy", "chick", "chide",
+    "chief", "child", "chili", "chill", "chime", "chirp", "choir", "choke", "chord", "chore",
+    "chose", "chuck", "chump", "chunk", "churn", "cider", "cigar", "cinch", "circa", "civic",
+    "civil", "clack", "claim", "clamp", "clang", "clank", "clash", "clasp", "class", "clean",
+    "clear", "cleat", "cleft", "clerk", "click", "cliff", "climb", "cling", "cloak", "clock",
+    "clone", "close", "cloth", "cloud", "clout", "clove", "clown", "cluck", "clued", "clump",
+    "clung", "coach", "coast", "cobra", "cocoa", "colon", "color", "comet", "comic", "comma",
+    "condo", "conic", "copse", "coral", "corer", "corny", "couch", "cough", "could", "count",
+    "coupe", "court", "coven", "cover", "covet", "cower", "crack", "craft", "cramp", "crane",
+    "crank", "crash", "crass", "crate", "crave", "crawl", "crazy", "creak", "cream", "credo",
+    "creed", "creek", "creep", "crept", "cress", "crest", "crick", "cried", "crier", "crime",
+    "crimp", "crisp", "croak", "crone", "crony", "crook", "cross", "croup", "crowd", "crown",
+    "cruel", "crumb", "crush", "crust", "crypt", "cubic", "cumin", "cupid", "curio", "curly",
+    "curry", "curse", "curve", "curvy", "cutie", "cyber", "cycle", "cynic", "daffy", "daily",
+    "dance", "dandy", "datum", "daunt", "dealt", "death", "debit", "debug", "debut", "decal",
+    "decay", "decor", "decoy", "decry", "defer", "deign", "deity", "delay", "delta", "delve",
+    "demon", "demur", "denim", "dense", "depot", "depth", "derby", "deter", "detox", "deuce",
+    "devil", "diary", "diner", "dingo", "dingy", "dirge", "dirty", "disco", "ditch", "ditto",
+    "ditty", "diver", "dizzy", "dodge", "dodgy", "dogma", "doing", "dolly", "donor", "donut",
+    "dopey", "doubt", "dough", "dowdy", "dowel", "downy", "dowry", "dozen", "draft", "drain",
+    "drama", "drawl", "drawn", "dread", "dream", "dress", "dried", "drier", "drift", "drill",
+    "drink", "drive", "droll", "drone", "drool", "droop", "dross", "drove", "drown", "druid",
+    "drunk", "dryer", "dryly", "duchy", "dully", "dummy", "dumpy", "dunce", "dusty", "duvet",
+    "dwarf", "dwell", "dwelt", "dying", "eager", "eagle", "early", "earth", "easel", "eaten",
+    "eater", "ebony", "eclat", "edict", "edify", "eerie", "egret", "eight", "eject", "eking",
+    "elate", "elbow", "elder", "elegy", "elfin", "elite", "elope", "elude", "email", "embed",
+    "ember", "emcee", "empty", "enact", "endow", "enemy", "enjoy", "ensue", "enter", "entry",
+    "envoy", "epoch", "epoxy", "equal", "equip", "erase", "erect", "erode", "error", "erupt",
+    "essay", "ethic", "ethos", "evade", "event", "every", "evict", "evoke", "exact", "exalt",
+    "excel", "exert", "exile", "exist", "expel", "extol", "extra", "exult", "fable", "facet",
+    "faded", "faint", "fairy", "faith", "false", "fancy", "farce", "fatal", "fated", "fault",
+    "feast", "feign", "feint", "fella", "felon", "femur", "fence", "feral", "ferry", "fetal",
+    "fetch", "fetid", "fetus", "fever", "fiber", "field", "fiend", "fiery", "fifth", "fifty",
+    "fight", "filly", "filmy", "filth", "final", "finch", "finer", "first", "fishy", "fixed",
+    "flail", "flair", "flaky", "flame", "flank", "flare", "flash", "flask", "fleet", "flesh",
+    "flick", "flier", "fling", "flint", "flirt", "float", "flock", "floor", "flora", "flour",
+    "flout", "flown", "fluff", "fluid", "fluke", "flume", "flung", "flunk", "flush", "flute",
+    "foamy", "focus", "foggy", "folly", "foray", "force", "forge", "forgo", "forte", "forth",
+    "forty", "forum", "found", "foyer", "frail", "frame", "frank", "fraud", "freak", "freed",
+    "freer", "fresh", "friar", "fried", "frill", "frisk", "fritz", "frock", "front", "frost",
+    "frown", "froze", "fruit", "fudge", "fugue", "fully", "fungi", "funky", "funny", "furor",
+    "furry", "fussy", "fuzzy", "gabby", "gamer", "gamma", "gamut", "gassy", "gaudy", "gauge",
+    "gaunt", "gauze", "gavel", "gawky", "gayer", "gazer", "gecko", "geese", "genie", "genre",
+    "getup", "ghost", "ghoul", "giant", "giddy", "girly", "girth", "given", "giver", "gland",
+    "glare", "glass", "glaze", "gleam", "glean", "glide", "glint", "gloat", "globe", "gloom",
+    "glory", "glove", "glyph", "gnash", "gnome", "godly", "golem", "goner", "goody", "gooey",
+    "goofy", "goose", "gorge", "gouge", "gourd", "grace", "grade", "grain", "grand", "grant",
+    "grape", "graph", "grasp", "grass", "grate", "grave", "gravy", "graze", "great", "greed",
+    "green", "greet", "grief", "grill", "grime", "grimy", "grind", "gripe", "groan", "groin",
+    "groom", "grope", "gross", "group", "grout", "grove", "growl", "grown", "gruel", "gruff",
+    "grunt", "guard", "guava", "guess", "guest", "guide", "guild", "guile", "guilt", "guise",
+    "gulch", "gully", "gumbo", "gummy", "guppy", "gusty", "habit", "hairy", "halve", "handy",
+    "happy", "hardy", "harem", "harpy", "harry", "harsh", "haste", "hasty", "hatch", "hater",
+    "haunt", "haven", "havoc", "hazel", "heady", "heart", "heath", "heave", "heavy", "hedge",
+    "hefty", "heist", "helix", "hence", "heron", "hilly", "hinge", "hippo", "hippy", "hitch",
+    "hoard", "hobby", "hoist", "homer", "honey", "honor", "hoody", "horde", "horse", "hotel",
+    "hotly", "hound", "house", "hovel", "hover", "howdy", "human", "humid", "humor", "humph",
+    "humus", "hunch", "hunky", "hurry", "husky", "hutch", "hydro", "hyena", "hyper", "icily",
+    "icing", "ideal", "idiom", "idiot", "idler", "idyll", "igloo", "iliac", "image", "imbue",
+    "impel", "imply", "inane", "inbox", "incur", "index", "inept", "inert", "infer", "ingot",
+    "inlay", "inlet", "inner", "input", "inter", "intro", "ionic", "irate", "irony", "islet",
+    "issue", "itchy", "ivory", "jaded", "jaunt", "jazzy", "jelly", "jewel", "jiffy", "jimmy",
+    "joint", "joist", "joker", "jolly", "joust", "judge", "juice", "juicy", "jumbo", "jumpy",
+    "junta", "juror", "kaput", "karma", "kayak", "kebab", "khaki", "kiosk", "kitty", "knack",
+    "knave", "knead", "kneed", "kneel", "knelt", "knife", "knock", "knoll", "known", "koala",
+    "krill", "label", "labor", "laden", "ladle", "lager", "lance", "lanky", "lapel", "lapse",
+    "large", "larva", "lasso", "latch", "later", "lathe", "latte", "laugh", "layer", "leafy",
+    "leaky", "leant", "leapt", "learn", "lease", "leash", "least", "leave", "ledge", "leech",
+    "leery", "lefty", "legal", "leggy", "lemon", "lemur", "leper", "level", "libel", "liege",
+    "lifer", "light", "lilac", "limbo", "limit", "linen", "liner", "lingo", "lipid", "lithe",
+    "liver", "livid", "llama", "loamy", "loath", "lobby", "local", "lodge", "lofty", "logic",
+    "login", "loopy", "loose", "lorry", "loser", "lotus", "louse", "lousy", "lover", "lower",
+    "lowly", "loyal", "lucid", "lucky", "lumen", "lumpy", "lunar", "lunch", "lunge", "lupus",
+    "lurch", "lurid", "lusty", "lying", "lyric", "macho", "madam", "madly", "mafia", "magic",
+    "major", "mambo", "mamma", "mango", "mania", "manic", "manly", "manor", "maple", "marry",
+    "marsh", "mason", "match", "matey", "mauve", "maxim", "maybe", "mayor", "mealy", "meant",
+    "meaty", "medal", "media", "medic", "melee", "melon", "mercy", "merge", "merit", "merry",
+    "messy", "metal", "midst", "might", "milky", "mimic", "mince", "miner", "minim", "minor",
+    "minty", "minus", "mirth", "miser", "misty", "mixed", "mocha", "modal", "model", "modem",
+    "mogul", "moist", "molar", "moldy", "money", "month", "moody", "moose", "moral", "moron",
+    "morph", "mossy", "motel", "motif", "motor", "motto", "moult", "mound", "mount", "mourn",
+    "mouse", "mouth", "mover", "movie", "mower", "mucky", "mucus", "muddy", "mulch", "mummy",
+    "munch", "mural", "murky", "mushy", "music", "musky", "musty", "myrrh", "nadir", "naive",
+    "nappy", "nasty", "naval", "navel", "needy", "nerdy", "nerve", "nervy", "never", "newer",
+    "newly", "nicer", "niche", "niece", "nifty", "night", "ninja", "ninny", "ninth", "nippy",
+    "noble", "nobly", "noise", "noisy", "nomad", "north", "notch", "noted", "novel", "nudge",
+    "nurse", "nutty", "nymph", "oaken", "obese", "occur", "ocean", "octal", "octet", "odder",
+    "oddly", "offal", "offer", "often", "olive", "ombre", "omega", "onion", "onset", "opera",
+    "opine", "opium", "optic", "orate", "orbit", "order", "organ", "other", "otter", "ought",
+    "ounce", "outdo", "outer", "outgo", "ovary", "ovate", "overt", "ovoid", "owing", "owner",
+    "oxide", "ozone", "paddy", "pagan", "paint", "paler", "palsy", "panda", "panel", "panic",
+    "pansy", "papal", "paper", "parer", "parka", "parry", "parse", "party", "pasta", "paste",
+    "pasty", "patch", "patio", "patsy", "patty", "pause", "payee", "payer", "peace", "pearl",
+    "pecan", "pedal", "penal", "pence", "penne", "penny", "perch", "peril", "perky", "pesky",
+    "pesto", "petal", "petty", "phase", "phone", "phony", "photo", "piano", "picky", "piece",
+    "piety", "piggy", "pilot", "pinch", "pinky", "pinto", "piper", "pique", "pitch", "pithy",
+    "pivot", "pixel", "pixie", "pizza", "place", "plaid", "plain", "plane", "plank", "plant",
+    "plate", "plaza", "plead", "pleat", "plied", "plier", "pluck", "plumb", "plume", "plump",
+    "plunk", "plush", "poesy", "point", "poker", "polar", "polka", "polyp", "pooch", "poppy",
+    "porch", "poser", "posit", "posse", "pouch", "pound", "pouty", "power", "prank", "prawn",
+    "preen", "press", "price", "prick", "pride", "prime", "primo", "print", "prism", "privy",
+    "prize", "probe", "prone", "prong", "proof", "prose", "proud", "prove", "prowl", "proxy",
+    "prude", "prune", "psalm", "pudgy", "puffy", "pulpy", "pulse", "punch", "pupil", "puppy",
+    "purer", "purge", "purse", "pushy", "putty", "quack", "quail", "qualm", "quark", "quart",
+    "quash", "quasi", "queen", "queer", "query", "quest", "queue", "quick", "quiet", "quill",
+    "quilt", "quirk", "quota", "quote", "rabbi", "rabid", "radar", "radii", "radio", "rainy",
+    "raise", "rally", "ramen", "ranch", "randy", "range", "rapid", "rarer", "raspy", "ratio",
+    "ratty", "raven", "rayon", "razor", "reach", "react", "ready", "realm", "rearm", "rebar",
+    "rebel", "rebus", "rebut", "recap", "recur", "recut", "reedy", "refer", "refit", "regal",
+    "rehab", "reign", "relax", "relic", "remit", "renal", "renew", "repay", "repel", "reply",
+    "rerun", "reset", "resin", "retch", "retro", "retry", "reuse", "revel", "revue", "rhino",
+    "rhyme", "rider", "ridge", "rifle", "right", "rigid", "rinse", "ripen", "riper", "risen",
+    "riser", "risky", "ritzy", "rival", "river", "rivet", "roach", "roast", "robin", "robot",
+    "rocky", "rodeo", "rogue", "roomy", "roost", "rotor", "rouge", "rough", "round", "route",
+    "rowdy", "rower", "royal", "ruddy", "rugby", "ruler", "rumba", "rumor", "rupee", "rural",
+    "rusty", "sable", "sadly", "saint", "salad", "salon", "salsa", "salty", "salve", "salvo",
+    "sandy", "saner", "sappy", "sassy", "satin", "satyr", "sauce", "saucy", "sauna", "saute",
+    "savor", "savvy", "scald", "scale", "scalp", "scaly", "scamp", "scant", "scare", "scarf",
+    "scary", "scene", "scent", "scoff", "scold", "scone", "scoop", "scope", "score", "scorn",
+    "scour", "scout", "scowl", "scram", "scrap", "scree", "screw", "scrub", "scrum", "sedan",
+    "seedy", "segue", "seize", "sense", "sepia", "serif", "serum", "serve", "setup", "seven",
+    "sever", "sewer", "shack", "shade", "shaft", "shake", "shaky", "shale", "shall", "shame",
+    "shank", "shape", "shard", "share", "sharp", "shawl", "shear", "sheen", "sheep", "sheer",
+    "sheet", "shelf", "shell", "shied", "shift", "shine", "shiny", "shire", "shirk", "shirt",
+    "shoal", "shock", "shone", "shook", "shoot", "shore", "shorn", "short", "shout", "shove",
+    "shown", "showy", "shrew", "shrub", "shrug", "shuck", "shunt", "shush", "siege", "sieve",
+    "sight", "sigma", "silky", "silly", "since", "sinew", "singe", "sinus", "siren", "sissy",
+    "sixth", "sixty", "skate", "skier", "skiff", "skill", "skimp", "skirt", "skulk", "skull",
+    "skunk", "slack", "slain", "slang", "slant", "slash", "slave", "sleek", "sleep", "sleet",
+    "slept", "slice", "slick", "slide", "slime", "slimy", "sling", "slink", "sloop", "slope",
+    "slosh", "sloth", "slump", "slung", "slunk", "slurp", "slush", "smack", "small", "smart",
+    "smash", "smear", "smell", "smelt", "smile", "smirk", "smite", "smith", "smock", "smoke",
+    "snack", "snail", "snake", "snare", "snarl", "sneak", "sneer", "snide", "sniff", "snipe",
+    "snoop", "snore", "snort", "snout", "snowy", "snuck", "snuff", "soapy", "sober", "soggy",
+    "solar", "solid", "solve", "sonic", "sooth", "sooty", "sorry", "sound", "south", "space",
+    "spade", "spare", "spark", "spasm", "spawn", "speak", "speck", "speed", "spell", "spelt",
+    "spend", "spent", "spice", "spicy", "spied", "spiel", "spike", "spiky", "spill", "spilt",
+    "spine", "spiny", "spire", "spite", "splat", "split", "spoil", "spoke", "spoof", "spook",
+    "spool", "spoon", "spore", "sport", "spout", "spray", "spree", "sprig", "spunk", "spurn",
+    "spurt", "squad", "squat", "squib", "stack", "staff", "stage", "staid", "stain", "stair",
+    "stake", "stale", "stalk", "stall", "stamp", "stand", "stank", "stare", "stark", "start",
+    "stash", "state", "stave", "stead", "steak", "steam", "steed", "steel", "steep", "stein",
+    "stern", "stick", "stiff", "still", "sting", "stink", "stint", "stock", "stoic", "stoke",
+    "stole", "stomp", "stone", "stony", "stood", "stool", "stoop", "store", "storm", "story",
+    "stout", "stove", "strap", "straw", "stray", "strip", "strut", "stuck", "study", "stuff",
+    "stump", "stung", "stunk", "stunt", "style", "suave", "sugar", "suing", "suite", "sulky",
+    "sully", "sumac", "sunny", "super", "surer", "surge", "surly", "sushi", "swami", "swamp",
+    "swarm", "swath", "swear", "sweat", "sweep", "sweet", "swell", "swept", "swift", "swill",
+    "swine", "swing", "swirl", "swish", "swoon", "swoop", "sword", "synod", "syrup", "tabby",
+    "table", "taboo", "tacit", "tacky", "taffy", "taint", "taken", "taker", "talon", "tamer",
+    "tango", "tangy", "taper", "tapir", "tardy", "tarot", "taste", "tasty", "taunt", "tawny",
+    "teach", "teary", "tease", "teeny", "teeth", "tempo", "tenet", "tenor", "tense", "tepid",
+    "terse", "testy", "thank", "theft", "their", "theme", "there", "these", "thick", "thief",
+    "thigh", "thing", "think", "third", "thong", "thorn", "those", "three", "threw", "throb",
+    "throw", "thumb", "thump", "thyme", "tiara", "tibia", "tidal", "tiger", "tight", "tilde",
+    "timer", "timid", "tipsy", "tired", "titan", "tithe", "title", "toast", "today", "toddy",
+    "token", "tonal", "tonic", "tooth", "topaz", "topic", "toque", "torch", "torso", "total",
+    "totem", "touch", "tough", "towel", "tower", "toxic", "toxin", "trace", "track", "tract",
+    "trade", "trail", "train", "tramp", "trash", "tread", "treat", "trend", "trial", "tribe",
+    "trice", "trick", "tried", "tripe", "trite", "troll", "troop", "trope", "trout", "trove",
+    "truce", "truck", "truly", "trump", "trunk", "truss", "trust", "truth", "tryst", "tubal",
+    "tuber", "tulip", "tulle", "tumor", "tunic", "turbo", "tutor", "twang", "tweak", "tweed",
+    "tweet", "twice", "twine", "twirl", "twist", "udder", "ulcer", "ultra", "umbra", "uncle",
+    "uncut", "under", "undid", "undue", "unfed", "unfit", "unify", "union", "unite", "unity",
+    "unlit", "unmet", "untie", "until", "unwed", "unzip", "upper", "upset", "urban", "usage",
+    "usher", "usual", "usurp", "utile", "utter", "vague", "valid", "value", "valve", "vapid",
+    "vapor", "vault", "vaunt", "vegan", "veldt", "venom", "venue", "verge", "verse", "video",
+    "vigil", "villa", "vinyl", "viola", "viper", "viral", "virus", "visit", "visor", "vista",
+    "vital", "vivid", "vocal", "vodka", "vogue", "voice", "voila", "vomit", "voter", "vouch",
+    "vowel", "wacky", "wafer", "wagon", "waist", "waive", "waltz", "warty", "waste", "watch",
+    "water", "weary", "weave", "wedge", "weedy", "weigh", "weird", "wench", "whack", "whale",
+    "wharf", "wheat", "wheel", "where", "which", "whiff", "while", "whine", "whiny", "whirl",
+    "whisk", "white", "whole", "widen", "widow", "width", "wield", "wight", "wimpy", "wince",
+    "winch", "windy", "wiper", "wispy", "witch", "witty", "woken", "woman", "woody", "wooer",
+    "wooly", "woozy", "wordy", "world", "worry", "worse", "worst", "worth", "would", "wound",
+    "wrath", "wreak", "wreck", "wrest", "wring", "wrist", "write", "wrong", "wrote", "wrung",
+    "wryly", "yacht", "yearn", "yeast", "yield", "yodel", "young", "yours", "youth", "yummy",
+    "zebra", "zesty", "zippy", "zonal",
+];
+
+pub static ALLOWED_GUESSES: [&str; 14855] = [
     "aahed", "aalii", "aapas", "aargh", "aarti", "abaca", "abaci", "aback", "abacs", "abaft",
     "abaht", "abaka", "abamp", "aband", "abase", "abash", "abask", "abate", "abaya", "abbas",
     "abbed", "abbes", "abbey", "abbot", "abcee", "abeam", "abear", "abeat", "abeer", "abele",
@@ -0,0 +1,45 @@
+use std::time::{Duration, Instant};
+
+use wordle_rust::animation::Animation;
+
+fn ms(n: u64) -> Duration {
+    Duration::from_millis(n)
+}
+
+#[test]
+fn reveal_flips_tiles_one_after_another() {
+    let start = Instant::now();
+    let reveal = Animation::reveal(2, 5, false, start);
+    assert_eq!(reveal.row(), 2);
+
+    let first = reveal.tile(0, start + ms(400));
+    let last = reveal.tile(4, start + ms(400));
+    assert!(!first.hidden);
+    assert!(last.hidden);
+
+    assert!(!reveal.is_finished(start + ms(500)));
+    assert!(reveal.is_finished(start + ms(5000)));
+    assert!(!reveal.tile(4, start + ms(5000)).hidden);
+}
+
+#[test]
+fn winning_reveal_bounces_after_the_flips() {
+    let start = Instant::now();
+    let plain = Animation::reveal(0, 5, false, start);
+    let won = Animation::reveal(0, 5, true, start);
+
+    let flips_done = start + ms(1100);
+    assert!(plain.is_finished(flips_done));
+    assert!(!won.is_finished(flips_done));
+    assert_eq!(won.tile(0, flips_done + ms(10)).offset_y, -1);
+    assert_eq!(won.tile(4, flips_done + ms(10)).offset_y, 0);
+}
+
+#[test]
+fn shake_moves_sideways_and_settles() {
+    let start = Instant::now();
+    let shake = Animation::shake(0, 5, start);
+    assert_ne!(shake.tile(0, start + ms(10)).offset_x, 0);
+    assert_eq!(shake.tile(0, start + ms(1000)).offset_x, 0);
+    assert!(shake.is_finished(start + ms(1000)));
+}
@@ -0,0 +1,75 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::widgets::Widget;
+
+use wordle_rust::board::Board;
+use wordle_rust::game_logic::check_word;
+use wordle_rust::theme;
+
+fn render(board: Board, width: u16, height: u16) -> Vec<String> {
+    let area = Rect::new(0, 0, width, height);
+    let mut buf = Buffer::empty(area);
+    board.render(area, &mut buf);
+    (0..height)
+        .map(|y| (0..width).map(|x| buf.get(x, y).symbol()).collect())
+        .collect()
+}
+
+#[test]
+fn draws_guesses_input_and_empty_rows() {
+    let guesses = vec![check_word("slate", "crane")];
+    let board = Board::new(&guesses, 5).input("cr").max_guesses(Some(3));
+
+    let lines = render(board, 29, 9);
+    assert_eq!(lines[1], "│ S │ │ L │ │ A │ │ T │ │ E │");
+    assert_eq!(lines[4], "│ C │ │ R │ │   │ │   │ │   │");
+    assert_eq!(lines[7], "│   │ │   │ │   │ │   │ │   │");
+}
+
+#[test]
+fn falls_back_to_compact_tiles() {
+    let guesses = vec![check_word("slate", "crane")];
+    let board = Board::new(&guesses, 5).max_guesses(Some(6));
+
+    let lines = render(board, 19, 6);
+    assert_eq!(lines[0], " S   L   A   T   E ");
+    assert_eq!(lines[5], " ·   ·   ·   ·   · ");
+}
+
+#[test]
+fn squeezes_long_words_into_narrow_areas() {
+    let board = Board::new(&[], 11)
+        .input("grandfather")
+        .max_guesses(Some(1));
+    assert_eq!(render(board, 32, 1)[0], format!("{:^32}", "GRANDFATHER"));
+
+    let board = Board::new(&[], 10).input("abundances").max_guesses(Some(1));
+    assert_eq!(
+        render(board, 32, 1)[0],
+        format!("{:^32}", " A  B  U  N  D  A  N  C  E  S ")
+    );
+}
+
+#[test]
+fn handles_more_guesses_than_fit_in_a_u16() {
+    // 65538 rows truncate to 2, which would fit as bordered tiles.
+    let board = Board::new(&[], 5).max_guesses(Some(65_538));
+
+    let lines = render(board, 29, 6);
+    assert_eq!(lines[0], "      ·   ·   ·   ·   ·      ");
+}
+
+#[test]
+fn marks_input_that_cannot_become_a_word() {
+    let area = Rect::new(0, 0, 29, 3);
+    let mut buf = Buffer::empty(area);
+    Board::new(&[], 5)
+        .input("xq")
+        .input_valid(false)
+        .render(area, &mut buf);
+    assert_eq!(buf.get(2, 1).symbol(), "X");
+    assert_eq!(buf.get(2, 1).fg, theme::DEFAULT.invalid);
+    assert!(buf.get(2, 1).modifier.contains(Modifier::UNDERLINED));
+    assert_eq!(buf.get(14, 1).fg, theme::DEFAULT.muted);
+}
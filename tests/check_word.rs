@@ -0,0 +1,98 @@
+use wordle_rust::game_logic::{check_word, elements_from_pattern, letter_statuses, Status};
+
+// G = green, Y = yellow, . = not in the word
+fn pattern(guess: &str, solution: &str) -> String {
+    check_word(guess, solution)
+        .iter()
+        .map(|e| e.status.code())
+        .collect()
+}
+
+#[test]
+fn scores_match_reference() {
+    let cases = [
+        // no repeated letters
+        ("crane", "crane", "GGGGG"),
+        ("crane", "audio", "..Y.."),
+        // guess repeats a letter the solution has once
+        ("speed", "abide", "..Y.Y"),
+        ("geese", "those", "...GG"),
+        ("eerie", "those", "....G"),
+        ("stare", "sassy", "G.Y.."),
+        ("allee", "apple", "GY..G"),
+        ("llama", "hello", "YY..."),
+        ("error", "robot", ".Y.G."),
+        // solution repeats a letter the guess has once
+        ("sassy", "stare", "GY..."),
+        ("apple", "allee", "G..YG"),
+        ("hello", "llama", "..YY."),
+        // both repeat the same letter
+        ("abbey", "kebab", "YYGY."),
+        ("kebab", "abbey", ".YGYY"),
+        ("sissy", "assay", "Y.G.G"),
+        ("level", "lever", "GGGG."),
+        ("lever", "level", "GGGG."),
+        ("mamma", "maxim", "GGY.."),
+        ("fluff", "offal", "YY.Y."),
+        ("offal", "fluff", ".YY.Y"),
+        ("radar", "array", "YY.GY"),
+        ("array", "radar", "YYYG."),
+        ("civic", "click", "GY..Y"),
+        ("steep", "sheep", "G.GGG"),
+        ("teeth", "sheet", "YYG.Y"),
+        ("xylyl", "lysyl", ".GYGG"),
+        // letter repeated many times
+        ("eeeee", "epees", "G.GG."),
+        ("epees", "eeeee", "G.GG."),
+    ];
+
+    for (guess, solution, expected) in cases {
+        assert_eq!(
+            pattern(guess, solution),
+            expected,
+            "guess {} against {}",
+            guess,
+            solution
+        );
+    }
+}
+
+#[test]
+fn elements_keep_guessed_letters() {
+    let elements = check_word("speed", "abide");
+    let letters: String = elements.iter().map(|e| e.c).collect();
+    assert_eq!(letters, "speed");
+}
+
+#[test]
+fn letter_statuses_keep_the_best_hint() {
+    let history = vec![check_word("trace", "crane"), check_word("crane", "crane")];
+    let statuses = letter_statuses(&history);
+    assert_eq!(statuses[&'t'], Status::Nothing);
+    assert_eq!(statuses[&'c'], Status::Green);
+    assert_eq!(statuses[&'e'], Status::Green);
+    assert_eq!(statuses.get(&'s'), None);
+}
+
+#[test]
+fn patterns_read_back_into_elements() {
+    let elements = elements_from_pattern("crane", "gY-x.").unwrap();
+    let statuses: Vec<Status> = elements.iter().map(|e| e.status).collect();
+    assert_eq!(
+        statuses,
+        [
+            Status::Green,
+            Status::Yellow,
+            Status::Nothing,
+            Status::Nothing,
+            Status::Nothing
+        ]
+    );
+    assert_eq!(elements[1].c, 'r');
+    assert_eq!(elements_from_pattern("crane", "gy.."), None);
+    assert_eq!(elements_from_pattern("crane", "gy..?"), None);
+
+    let scored = check_word("crane", "react");
+    let codes: String = scored.iter().map(|e| e.status.code()).collect();
+    assert_eq!(elements_from_pattern("crane", &codes), Some(scored));
+}
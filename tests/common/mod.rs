@@ -0,0 +1,41 @@
+// Each test crate uses only some of the helpers.
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::PathBuf;
+
+use wordle_rust::word::Word;
+
+pub fn word(w: &str) -> Word {
+    Word::new(w).unwrap()
+}
+
+pub fn words(list: &[&str]) -> Vec<Word> {
+    list.iter().map(|w| word(w)).collect()
+}
+
+/// An empty directory under the system temp dir, removed again on drop.
+pub struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    /// The directory isn't created, so code under test has to create it.
+    pub fn new(name: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("wordle-rust-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        Self { path }
+    }
+
+    /// `file` inside the directory.
+    pub fn join(&self, file: &str) -> PathBuf {
+        self.path.join(file)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
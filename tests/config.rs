@@ -0,0 +1,81 @@
+mod common;
+
+use std::fs;
+use std::num::NonZeroUsize;
+
+use common::TempDir;
+use wordle_rust::config::{Config, ConfigError, Setting};
+use wordle_rust::keyboard::KeyboardLayout;
+use wordle_rust::Difficulty;
+
+#[test]
+fn missing_keys_keep_their_defaults() {
+    let config = Config::from_toml("word_length = 6\ndifficulty = \"hard\"").unwrap();
+    assert_eq!(config.word_length, 6);
+    assert_eq!(config.difficulty, Difficulty::Hard);
+    assert_eq!(config.max_guesses, NonZeroUsize::new(6));
+    assert_eq!(config.keyboard_layout, KeyboardLayout::Qwerty);
+    assert!(config.animations);
+    assert_eq!(Config::from_toml("").unwrap(), Config::default());
+}
+
+#[test]
+fn invalid_settings_are_errors() {
+    assert!(matches!(
+        Config::from_toml("word_length = 12"),
+        Err(ConfigError::Invalid(_))
+    ));
+    assert!(matches!(
+        Config::from_toml("max_guesses = 0"),
+        Err(ConfigError::Parse(_))
+    ));
+    assert!(matches!(
+        Config::from_toml("max_guesses = \"lots\""),
+        Err(ConfigError::Parse(_))
+    ));
+    assert!(matches!(
+        Config::from_toml("colour = \"red\""),
+        Err(ConfigError::Parse(_))
+    ));
+}
+
+#[test]
+fn saved_config_loads_again() {
+    let dir = TempDir::new("config");
+    let path = dir.join("config.toml");
+    assert_eq!(Config::load(&path).unwrap(), Config::default());
+
+    let config = Config {
+        max_guesses: None,
+        keyboard_layout: KeyboardLayout::Dvorak,
+        animations: false,
+        answers: Some("answers.txt".into()),
+        ..Config::default()
+    };
+    config.save(&path).unwrap();
+    let text = fs::read_to_string(&path).unwrap();
+    assert!(text.contains("max_guesses = \"unlimited\""));
+    assert_eq!(Config::load(&path).unwrap(), config);
+}
+
+#[test]
+fn settings_step_through_their_choices() {
+    let mut config = Config::default();
+    let lengths = [4, 5, 6];
+    let themes = ["default", "high-contrast", "monochrome"];
+
+    config.step(Setting::WordLength, true, &lengths, &themes);
+    assert_eq!(config.word_length, 6);
+    config.step(Setting::WordLength, true, &lengths, &themes);
+    assert_eq!(config.word_length, 4);
+
+    config.step(Setting::Theme, false, &lengths, &themes);
+    assert_eq!(config.theme, "monochrome");
+
+    config.max_guesses = NonZeroUsize::new(12);
+    config.step(Setting::MaxGuesses, true, &lengths, &themes);
+    assert_eq!(config.value_text(Setting::MaxGuesses), "unlimited");
+
+    config.step(Setting::Animations, true, &lengths, &themes);
+    assert_eq!(config.value_text(Setting::Animations), "off");
+}
@@ -0,0 +1,33 @@
+use std::collections::HashSet;
+
+use chrono::Days;
+use wordle_rust::daily;
+use wordle_rust::word_list::ANSWERS;
+
+#[test]
+fn numbers_count_days_since_epoch() {
+    let epoch = daily::epoch();
+    assert_eq!(daily::puzzle_number(epoch), Some(0));
+    assert_eq!(daily::puzzle_number(epoch + Days::new(123)), Some(123));
+    assert_eq!(daily::puzzle_number(epoch - Days::new(1)), None);
+}
+
+#[test]
+fn solution_is_stable_and_does_not_repeat_within_a_cycle() {
+    assert_eq!(daily::solution(&ANSWERS, 42), daily::solution(&ANSWERS, 42));
+
+    let cycle: HashSet<&str> = (0..ANSWERS.len() as u32)
+        .map(|n| daily::solution(&ANSWERS, n))
+        .collect();
+    assert_eq!(cycle.len(), ANSWERS.len());
+}
+
+#[test]
+fn solutions_are_pinned() {
+    // Changing these changes everyone's daily word.
+    let words: Vec<&str> = [0, 1, 42, 1000]
+        .iter()
+        .map(|&n| daily::solution(&ANSWERS, n))
+        .collect();
+    assert_eq!(words, ["trice", "pitch", "recut", "loopy"]);
+}
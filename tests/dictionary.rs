@@ -0,0 +1,66 @@
+use wordle_rust::dictionary::{LineError, LineErrorKind};
+use wordle_rust::word::Word;
+use wordle_rust::{Dictionary, DictionaryError};
+
+#[test]
+fn parses_text_and_skips_comments() {
+    let dictionary = Dictionary::from_text("# themed\nCrane\n\nslate\n").unwrap();
+    assert_eq!(dictionary.words(5), ["crane", "slate"]);
+    assert!(dictionary.contains("crane"));
+    assert!(!dictionary.contains("trace"));
+}
+
+#[test]
+fn buckets_words_by_length() {
+    let dictionary = Dictionary::from_text("crane\ncrate\nplanet\nword\n").unwrap();
+    assert_eq!(dictionary.lengths().collect::<Vec<_>>(), [4, 5, 6]);
+    assert_eq!(dictionary.words(6), ["planet"]);
+    assert_eq!(dictionary.packed(6), [Word::new("planet").unwrap()]);
+    assert!(dictionary.words(7).is_empty());
+    assert!(dictionary.contains_word(Word::new("word").unwrap()));
+    assert!(!dictionary.contains("Word"));
+}
+
+#[test]
+fn parses_json() {
+    let dictionary = Dictionary::from_json(r#"["crane", "slate"]"#).unwrap();
+    assert_eq!(dictionary.len(), 2);
+}
+
+#[test]
+fn reports_every_bad_line() {
+    let text = "crane\nabc\ncr4ne\nslate\nCRANE\n";
+    let errors = match Dictionary::from_text(text) {
+        Err(DictionaryError::InvalidLines(errors)) => errors,
+        other => panic!("expected invalid lines, got {:?}", other),
+    };
+
+    assert_eq!(
+        errors,
+        [
+            LineError {
+                line: 2,
+                word: "abc".to_string(),
+                kind: LineErrorKind::WrongLength { found: 3 },
+            },
+            LineError {
+                line: 3,
+                word: "cr4ne".to_string(),
+                kind: LineErrorKind::NonAlphabetic,
+            },
+            LineError {
+                line: 5,
+                word: "crane".to_string(),
+                kind: LineErrorKind::Duplicate { first_line: 1 },
+            },
+        ]
+    );
+}
+
+#[test]
+fn rejects_empty_dictionary() {
+    assert!(matches!(
+        Dictionary::from_text("# nothing here\n"),
+        Err(DictionaryError::Empty)
+    ));
+}
@@ -0,0 +1,102 @@
+use wordle_rust::game_logic::{
+    check_hard_mode, check_ultra_hard_mode, check_word, Element, HardModeViolation, Knowledge,
+};
+
+fn history(guesses: &[&str], solution: &str) -> Vec<Vec<Element>> {
+    guesses.iter().map(|g| check_word(g, solution)).collect()
+}
+
+#[test]
+fn greens_must_stay_in_place() {
+    let history = history(&["scare"], "store");
+    assert_eq!(
+        check_hard_mode("slate", &history),
+        Err(HardModeViolation::MissingGreen {
+            position: 3,
+            letter: 'r'
+        })
+    );
+    assert_eq!(
+        check_hard_mode("slate", &history).unwrap_err().to_string(),
+        "4th letter must be R"
+    );
+    assert_eq!(check_hard_mode("store", &history), Ok(()));
+}
+
+#[test]
+fn yellows_must_be_reused() {
+    let history = history(&["salty"], "tasks");
+    assert_eq!(
+        check_hard_mode("tacky", &history).unwrap_err().to_string(),
+        "Guess must contain S"
+    );
+    assert_eq!(check_hard_mode("tasks", &history), Ok(()));
+}
+
+#[test]
+fn repeated_hints_require_repeated_letters() {
+    // Two E's in "geese" are shown yellow, so a guess with one E is not enough.
+    let history = history(&["geese"], "elder");
+    assert_eq!(
+        check_hard_mode("cheap", &history),
+        Err(HardModeViolation::MissingYellow {
+            letter: 'e',
+            count: 2
+        })
+    );
+    assert_eq!(
+        check_hard_mode("cheap", &history).unwrap_err().to_string(),
+        "Guess must contain 2 E's"
+    );
+    assert_eq!(check_hard_mode("elder", &history), Ok(()));
+}
+
+#[test]
+fn grey_letters_may_be_reused() {
+    let history = history(&["crane"], "moist");
+    assert_eq!(check_hard_mode("crane", &history), Ok(()));
+}
+
+#[test]
+fn knowledge_tracks_letter_bounds() {
+    // "eerie" against "those": one E is green, the others are grey.
+    let knowledge = Knowledge::from_history(&history(&["eerie"], "those"));
+    assert_eq!(knowledge.greens, [None, None, None, None, Some('e')]);
+    assert_eq!(knowledge.min_counts.get(&'e'), Some(&1));
+    assert_eq!(knowledge.max_counts.get(&'e'), Some(&1));
+    assert_eq!(knowledge.max_counts.get(&'r'), Some(&0));
+    assert!(knowledge.is_consistent("those"));
+    assert!(!knowledge.is_consistent("geese"));
+}
+
+#[test]
+fn ultra_hard_rejects_what_is_ruled_out() {
+    let history = history(&["salty"], "tasks");
+    assert_eq!(
+        check_ultra_hard_mode("tails", &history),
+        Err(HardModeViolation::AbsentLetter { letter: 'l' })
+    );
+    assert_eq!(
+        check_ultra_hard_mode("sabot", &history)
+            .unwrap_err()
+            .to_string(),
+        "1st letter can't be S"
+    );
+    assert_eq!(check_ultra_hard_mode("tasks", &history), Ok(()));
+
+    let history = self::history(&["eerie"], "those");
+    assert_eq!(
+        check_ultra_hard_mode("geese", &history),
+        Err(HardModeViolation::KnownWrongPosition {
+            position: 1,
+            letter: 'e'
+        })
+    );
+    assert_eq!(
+        check_ultra_hard_mode("obese", &history),
+        Err(HardModeViolation::TooMany {
+            letter: 'e',
+            max: 1
+        })
+    );
+}
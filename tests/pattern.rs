@@ -0,0 +1,68 @@
+mod common;
+
+use common::{word, words, TempDir};
+use wordle_rust::game_logic::check_word;
+use wordle_rust::pattern::{Pattern, PatternError, PatternMatrix};
+use wordle_rust::WordLists;
+
+#[test]
+fn score_agrees_with_check_word() {
+    let lists = WordLists::builtin();
+    let answers = lists.answers.words(5);
+    let guesses: Vec<&String> = answers.iter().chain(lists.guesses.words(5)).collect();
+    for guess in guesses.iter().step_by(37) {
+        for answer in answers.iter().step_by(11) {
+            let elements = check_word(guess, answer);
+            let pattern = Pattern::score(word(guess), word(answer)).unwrap();
+            assert_eq!(Some(pattern), Pattern::from_elements(&elements));
+            let statuses: Vec<_> = elements.iter().map(|e| e.status).collect();
+            assert_eq!(pattern.statuses(5), statuses, "{} vs {}", guess, answer);
+        }
+    }
+}
+
+#[test]
+fn patterns_fit_in_a_byte() {
+    let score = |guess, solution| Pattern::score(word(guess), word(solution));
+    assert_eq!(score("crane", "crane").unwrap().value(), 242);
+    assert_eq!(score("abcde", "fghij").unwrap().value(), 0);
+    // Y.Y.G: the last e is green, so only the first e is yellow.
+    assert_eq!(score("eerie", "there").unwrap().value(), 81 + 9 + 2);
+    assert_eq!(score("crane", "cranes"), None);
+    assert_eq!(score("shelve", "shelve"), None);
+}
+
+#[test]
+fn matrix_is_cached_and_mapped() {
+    let guesses = words(&["crane", "slate", "eerie"]);
+    let answers = words(&["there", "crane", "poppy", "slate"]);
+    let matrix = PatternMatrix::build(&guesses, &answers).unwrap();
+    for (g, &guess) in guesses.iter().enumerate() {
+        for (a, &answer) in answers.iter().enumerate() {
+            assert_eq!(Some(matrix.get(g, a)), Pattern::score(guess, answer));
+        }
+    }
+
+    let dir = TempDir::new("patterns");
+    let path = dir.join("patterns.bin");
+    assert!(PatternMatrix::load(&path, &guesses, &answers).is_err());
+    let built = PatternMatrix::load_or_build(&path, &guesses, &answers).unwrap();
+    assert!(!built.is_mapped());
+
+    let loaded = PatternMatrix::load(&path, &guesses, &answers).unwrap();
+    assert!(loaded.is_mapped());
+    assert_eq!(loaded.get(2, 0), matrix.get(2, 0));
+    assert_eq!(loaded.get(1, 3), matrix.get(1, 3));
+
+    let other = words(&["there", "crane", "poppy", "stale"]);
+    assert!(matches!(
+        PatternMatrix::load(&path, &guesses, &other),
+        Err(PatternError::InvalidCache(_))
+    ));
+}
+
+#[test]
+fn rejects_words_without_a_pattern() {
+    let result = PatternMatrix::build(&words(&["crane", "cranes"]), &words(&["slate"]));
+    assert!(matches!(result, Err(PatternError::UnsupportedWord(w)) if w == "cranes"));
+}
@@ -0,0 +1,17 @@
+use wordle_rust::rng::SplitMix64;
+
+#[test]
+fn matches_the_reference_output() {
+    let mut rng = SplitMix64::new(0);
+    assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+    assert_eq!(rng.next_u64(), 0x6e78_9e6a_a1b9_65f4);
+}
+
+#[test]
+fn shuffle_keeps_every_item() {
+    let mut items: Vec<u32> = (0..100).collect();
+    SplitMix64::new(7).shuffle(&mut items);
+    assert_ne!(items, (0..100).collect::<Vec<_>>());
+    items.sort();
+    assert_eq!(items, (0..100).collect::<Vec<_>>());
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use std::sync::Arc;
+
+use common::TempDir;
+use wordle_rust::save::{SaveFile, SaveStore, SavedGame};
+use wordle_rust::{Difficulty, Game, GameConfig, GameMode, GameState, WordLists};
+
+#[test]
+fn restored_game_continues_where_it_stopped() {
+    let words = Arc::new(WordLists::builtin());
+    let config = GameConfig {
+        difficulty: Difficulty::Hard,
+        ..GameConfig::default()
+    };
+    let mut game = Game::seeded(words.clone(), config, 7);
+    let solution = game.solution().to_string();
+    let guess = if solution == "crane" {
+        "slate"
+    } else {
+        "crane"
+    };
+    game.submit_guess(guess).unwrap();
+
+    let saved = SavedGame::from_game(&game);
+    let mut restored = saved.restore(words);
+    assert_eq!(restored.solution(), solution);
+    assert_eq!(restored.guesses(), game.guesses());
+    assert_eq!(restored.mode(), GameMode::Practice { seed: Some(7) });
+    assert_eq!(restored.config(), &config);
+    assert_eq!(restored.remaining_attempts(), Some(5));
+    assert!(restored.elapsed() >= saved.elapsed);
+
+    restored.submit_guess(&solution).unwrap();
+    assert_eq!(restored.state(), GameState::Won);
+}
+
+#[test]
+fn finished_practice_games_are_not_kept() {
+    let words = Arc::new(WordLists::builtin());
+    let mut game = Game::new("crane", words, GameConfig::default());
+    let mut file = SaveFile::default();
+
+    file.store(&game);
+    assert_eq!(file.practice, None);
+
+    game.submit_guess("slate").unwrap();
+    file.store(&game);
+    assert!(file.practice.is_some());
+
+    game.submit_guess("crane").unwrap();
+    file.store(&game);
+    assert_eq!(file.practice, None);
+}
+
+#[test]
+fn saved_games_allowing_no_guesses_are_rejected() {
+    let game = Game::new(
+        "crane",
+        Arc::new(WordLists::builtin()),
+        GameConfig::default(),
+    );
+    let mut json = serde_json::to_value(SavedGame::from_game(&game)).unwrap();
+    json["config"]["max_guesses"] = 0.into();
+    assert!(serde_json::from_value::<SavedGame>(json).is_err());
+}
+
+#[test]
+fn inconsistent_saves_are_dropped_on_load() {
+    let words = Arc::new(WordLists::builtin());
+    let mut game = Game::new("crane", words, GameConfig::default());
+    game.submit_guess("slate").unwrap();
+    let saved = SavedGame::from_game(&game);
+    assert!(saved.is_valid());
+
+    let dir = TempDir::new("invalid");
+    let store = SaveStore::new(dir.join("save.json"));
+    let broken = [
+        SavedGame {
+            solution: "cranes".to_string(),
+            ..saved.clone()
+        },
+        SavedGame {
+            guesses: vec!["slat".to_string()],
+            ..saved.clone()
+        },
+        SavedGame {
+            solution: "c".repeat(40),
+            config: GameConfig {
+                word_len: 40,
+                ..saved.config
+            },
+            ..saved.clone()
+        },
+    ];
+    for practice in broken {
+        assert!(!practice.is_valid());
+        let file = SaveFile {
+            practice: Some(practice),
+            daily: None,
+        };
+        store.save(&file).unwrap();
+        assert_eq!(store.load(), SaveFile::default());
+    }
+}
+
+#[test]
+fn daily_slot_matches_puzzle_number() {
+    let words = Arc::new(WordLists::builtin());
+    let mut game = Game::daily(words.clone(), GameConfig::default(), 42);
+    let solution = game.solution().to_string();
+    game.submit_guess(&solution).unwrap();
+
+    let dir = TempDir::new("save");
+    let store = SaveStore::new(dir.join("save.json"));
+    store.store(&game).unwrap();
+
+    let file = store.load();
+    assert!(file.daily(41).is_none());
+    let saved = file.daily(42).unwrap();
+    assert_eq!(saved.restore(words).state(), GameState::Won);
+}
+
+#[test]
+fn concurrent_stores_keep_both_slots() {
+    let words = Arc::new(WordLists::builtin());
+    let mut daily = Game::daily(words.clone(), GameConfig::default(), 42);
+    daily.submit_guess("slate").unwrap();
+    let mut practice = Game::new("crane", words, GameConfig::default());
+    practice.submit_guess("slate").unwrap();
+
+    let dir = TempDir::new("concurrent");
+    let store = SaveStore::new(dir.join("save.json"));
+    std::thread::scope(|s| {
+        for game in [&daily, &practice] {
+            let store = &store;
+            s.spawn(move || {
+                for _ in 0..50 {
+                    store.store(game).unwrap();
+                }
+            });
+        }
+    });
+
+    let file = store.load();
+    assert!(file.daily(42).is_some());
+    assert!(file.practice.is_some());
+}
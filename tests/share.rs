@@ -0,0 +1,54 @@
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+
+use wordle_rust::share::{osc52, share_text};
+use wordle_rust::{Difficulty, Game, GameConfig, GameMode, WordLists};
+
+#[test]
+fn grid_shows_statuses_without_letters() {
+    let words = Arc::new(WordLists::builtin());
+    let config = GameConfig {
+        difficulty: Difficulty::Hard,
+        ..GameConfig::default()
+    };
+    let mut game = Game::daily(words, config, 123);
+    let solution = game.solution().to_string();
+    let guess = if solution == "crane" {
+        "slate"
+    } else {
+        "crane"
+    };
+    game.submit_guess(guess).unwrap();
+    game.submit_guess(&solution).unwrap();
+
+    let text = share_text(&game, false);
+    let mut lines = text.lines();
+    assert_eq!(lines.next(), Some("Wordle-rust 123 2/6*"));
+    assert_eq!(lines.next(), Some(""));
+    assert_eq!(lines.next().unwrap().chars().count(), 5);
+    assert_eq!(lines.next(), Some("🟩🟩🟩🟩🟩"));
+    assert_eq!(lines.next(), None);
+    assert!(!text.to_lowercase().contains(&solution));
+}
+
+#[test]
+fn lost_colorblind_practice_game() {
+    let words = Arc::new(WordLists::builtin());
+    let config = GameConfig {
+        max_guesses: NonZeroUsize::new(1),
+        ..GameConfig::default()
+    };
+    let mut game = Game::new("crane", words, config);
+    assert_eq!(game.mode(), GameMode::Practice { seed: None });
+    game.submit_guess("trace").unwrap();
+
+    assert_eq!(
+        share_text(&game, true),
+        "Wordle-rust practice X/1\n\n⬛🟧🟧🟦🟧"
+    );
+}
+
+#[test]
+fn osc52_encodes_base64() {
+    assert_eq!(osc52("hi"), "\x1b]52;c;aGk=\x07");
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use common::{word, words};
+use wordle_rust::game_logic::{check_word, Status};
+use wordle_rust::pattern::PatternMatrix;
+use wordle_rust::solver::Solver;
+use wordle_rust::word::Feedback;
+
+fn feedback(guess: &str, solution: &str) -> Feedback {
+    word(guess).score(word(solution))
+}
+
+#[test]
+fn feedback_keeps_only_matching_candidates() {
+    let answers = words(&["crane", "crate", "trace", "react", "slate"]);
+    let mut solver = Solver::new(answers.clone(), answers.clone());
+
+    solver.apply(word("crane"), feedback("crane", "trace"));
+    for candidate in solver.candidates() {
+        assert_eq!(
+            check_word("crane", &candidate.to_string()),
+            check_word("crane", "trace")
+        );
+    }
+    assert_eq!(solver.candidates(), words(&["trace"]));
+}
+
+#[test]
+fn impossible_feedback_keeps_the_candidates() {
+    let answers = words(&["crane", "crate", "trace", "react", "slate"]);
+    let mut solver = Solver::new(answers.clone(), answers);
+
+    assert!(solver.try_apply(word("lucky"), feedback("lucky", "crate")));
+    let candidates = solver.candidates();
+    assert_eq!(candidates, words(&["crane", "crate", "trace", "react"]));
+
+    let typo = Feedback::from_statuses("gyyyy".chars().map(|c| Status::from_code(c).unwrap()));
+    assert!(!solver.try_apply(word("crane"), typo.unwrap()));
+    assert_eq!(solver.candidates(), candidates);
+}
+
+#[test]
+fn entropy_counts_how_candidates_split() {
+    let answers = words(&["bbbbb", "ccccc", "ddddd", "eeeee"]);
+    let guesses = words(&["bcdex", "bbbbb", "zzzzz"]);
+    let solver = Solver::new(guesses, answers);
+
+    // Every answer gives bcdex a different pattern.
+    assert!((solver.entropy(word("bcdex")) - 2.0).abs() < 1e-9);
+    // bbbbb only tells bbbbb apart from the rest.
+    assert!((solver.entropy(word("bbbbb")) - 0.811_278).abs() < 1e-6);
+    assert_eq!(solver.entropy(word("zzzzz")), 0.0);
+
+    let best = solver.best_guesses(2);
+    assert_eq!(best[0].word, word("bcdex"));
+    assert!(!best[0].is_candidate);
+    assert_eq!(best[1].word, word("bbbbb"));
+}
+
+#[test]
+fn ties_prefer_possible_solutions() {
+    let answers = words(&["fight", "might"]);
+    let guesses = words(&["fmxxx", "fight", "might"]);
+    let solver = Solver::new(guesses, answers);
+
+    let best = solver.best_guesses(3);
+    assert!(best.iter().all(|s| (s.entropy - 1.0).abs() < 1e-9));
+    assert_eq!(best[0].word, word("fight"));
+    assert_eq!(best[1].word, word("might"));
+    assert_eq!(best[2].word, word("fmxxx"));
+}
+
+#[test]
+fn matrix_gives_the_same_suggestions() {
+    let answers = words(&["crane", "crate", "trace", "react", "slate", "eerie"]);
+    let guesses = words(&["crane", "slate", "eerie", "toils", "roate"]);
+    let mut plain = Solver::new(guesses.clone(), answers.clone());
+    let solver = Solver::new(guesses, answers);
+    let matrix = PatternMatrix::build(solver.guesses(), solver.answers()).unwrap();
+    let mut fast = solver.with_matrix(matrix);
+
+    assert_eq!(plain.best_guesses(5), fast.best_guesses(5));
+    plain.apply(word("slate"), feedback("slate", "crate"));
+    fast.apply(word("slate"), feedback("slate", "crate"));
+    assert_eq!(plain.candidates(), fast.candidates());
+    assert_eq!(plain.best_guesses(5), fast.best_guesses(5));
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use std::fs;
+
+use common::TempDir;
+use wordle_rust::stats::{Stats, StatsStore};
+use wordle_rust::Outcome;
+
+#[test]
+fn tracks_streaks_and_distribution() {
+    let mut stats = Stats::default();
+    stats.record(Outcome::Won { attempts: 3 });
+    stats.record(Outcome::Won { attempts: 3 });
+    stats.record(Outcome::Lost);
+    stats.record(Outcome::Won { attempts: 1 });
+
+    assert_eq!(stats.played, 4);
+    assert_eq!(stats.wins, 3);
+    assert_eq!(stats.win_rate(), 75);
+    assert_eq!(stats.current_streak, 1);
+    assert_eq!(stats.max_streak, 2);
+    assert_eq!(stats.guess_distribution, [1, 0, 2]);
+}
+
+#[test]
+fn store_persists_games() {
+    let dir = TempDir::new("persist");
+    let store = StatsStore::new(dir.join("stats.json"));
+    store.record(Outcome::Won { attempts: 2 }).unwrap();
+    store.record(Outcome::Lost).unwrap();
+
+    let stats = store.load().unwrap();
+    assert_eq!(stats.played, 2);
+    assert_eq!(stats.guess_distribution, [0, 1]);
+}
+
+#[test]
+fn store_recovers_from_corrupt_file() {
+    let dir = TempDir::new("corrupt");
+    let path = dir.join("stats.json");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "{ not json").unwrap();
+
+    let store = StatsStore::new(path.clone());
+    assert_eq!(store.load().unwrap(), Stats::default());
+    assert!(path.with_extension("json.corrupt").exists());
+    assert_eq!(store.record(Outcome::Lost).unwrap().played, 1);
+}
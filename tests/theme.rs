@@ -0,0 +1,44 @@
+use ratatui::style::Color;
+
+use wordle_rust::game_logic::Status;
+use wordle_rust::theme::{self, ThemeError, Themes};
+
+#[test]
+fn user_themes_override_only_what_they_set() {
+    let themes = Themes::from_toml(
+        r##"
+        [mine]
+        correct = "#00ff00"
+        present = "magenta"
+        "##,
+    )
+    .unwrap();
+
+    let mine = themes.get("mine").unwrap();
+    assert_eq!(mine.correct, Color::Rgb(0, 255, 0));
+    assert_eq!(mine.present, Color::Magenta);
+    assert_eq!(mine.absent, theme::DEFAULT.absent);
+    assert_eq!(themes.get("monochrome").unwrap(), &theme::MONOCHROME);
+}
+
+#[test]
+fn rejects_unknown_fields_and_names() {
+    assert!(matches!(
+        Themes::from_toml("[mine]\ncorect = \"red\""),
+        Err(ThemeError::Toml(_))
+    ));
+    let err = Themes::default().get("nope").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unknown theme \"nope\", available: default, high-contrast, monochrome"
+    );
+}
+
+#[test]
+fn monochrome_marks_hints_with_glyphs() {
+    let mono = theme::MONOCHROME;
+    assert_eq!(mono.label('a', Some(Status::Green)), "[A]");
+    assert_eq!(mono.label('a', Some(Status::Yellow)), "(A)");
+    assert_eq!(mono.label('a', Some(Status::Nothing)), " A ");
+    assert_eq!(theme::DEFAULT.label('a', Some(Status::Green)), " A ");
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use common::word;
+use wordle_rust::game_logic::{check_word, Knowledge, Status};
+use wordle_rust::word::{Feedback, Word};
+use wordle_rust::WordLists;
+
+#[test]
+fn packs_letters_and_counts() {
+    let w = word("letter");
+    assert_eq!(w.len(), 6);
+    assert_eq!(w.to_string(), "letter");
+    assert_eq!(w.letter(3), 't');
+    assert_eq!(w.count('t'), 2);
+    assert_eq!(w.count('e'), 2);
+    assert_eq!(w.count('a'), 0);
+    assert!(w.contains('r'));
+    assert!(!w.contains('s'));
+    assert_eq!(w.presence(), (1 << 4) | (1 << 11) | (1 << 17) | (1 << 19));
+
+    assert_eq!(Word::new("Crane"), None);
+    assert_eq!(Word::new(""), None);
+    assert_eq!(Word::new("abcdefghijkl"), None);
+    assert!(Word::new("abcdefghijk").is_some());
+}
+
+#[test]
+fn words_sort_alphabetically() {
+    let mut words: Vec<Word> = ["slate", "crane", "cran", "zebra", "cranes"]
+        .iter()
+        .map(|w| word(w))
+        .collect();
+    words.sort();
+    let sorted: Vec<String> = words.iter().map(Word::to_string).collect();
+    assert_eq!(sorted, ["cran", "crane", "cranes", "slate", "zebra"]);
+}
+
+#[test]
+fn score_agrees_with_check_word_for_every_length() {
+    let lists = WordLists::builtin();
+    for len in lists.answers.lengths() {
+        let answers = lists.answers.words(len);
+        for guess in answers.iter().step_by(41) {
+            for answer in answers.iter().step_by(13) {
+                let feedback = word(guess).score(word(answer));
+                let statuses: Vec<Status> =
+                    check_word(guess, answer).iter().map(|e| e.status).collect();
+                assert!(feedback.statuses().eq(statuses.iter().copied()));
+                assert_eq!(Feedback::from_statuses(statuses), Some(feedback));
+            }
+        }
+    }
+}
+
+#[test]
+fn feedback_value_matches_base_three() {
+    let feedback = word("eerie").score(word("there"));
+    assert_eq!(feedback.value(), 81 + 9 + 2);
+    assert!(!feedback.is_win());
+    assert!(word("crane").score(word("crane")).is_win());
+    assert_eq!(Feedback::from_statuses([Status::Green; 12]), None);
+}
+
+#[test]
+fn packed_filtering_agrees_with_strings() {
+    let lists = WordLists::builtin();
+    let answers = lists.answers.words(5);
+    for (guess, solution) in [("eerie", "those"), ("crane", "react"), ("speed", "abide")] {
+        let knowledge = Knowledge::from_history(&[check_word(guess, solution)]);
+        for answer in answers {
+            assert_eq!(
+                knowledge.matches(word(answer)),
+                knowledge.check_ultra_hard(answer).is_ok(),
+                "{} after {}",
+                answer,
+                guess
+            );
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use wordle_rust::word::Word;
+use wordle_rust::word_index::WordIndex;
+use wordle_rust::WordLists;
+
+fn index(words: &[&str]) -> WordIndex {
+    WordIndex::new(words.iter().map(|w| Word::new(w).unwrap()))
+}
+
+fn strings(words: &[Word]) -> Vec<String> {
+    words.iter().map(Word::to_string).collect()
+}
+
+#[test]
+fn finds_words_by_binary_search() {
+    let index = index(&["slate", "crane", "cranes", "crate", "crane"]);
+    assert_eq!(index.len(), 4);
+    assert_eq!(
+        strings(index.words()),
+        ["crane", "cranes", "crate", "slate"]
+    );
+    assert!(index.contains(Word::new("cranes").unwrap()));
+    assert!(!index.contains(Word::new("cran").unwrap()));
+    assert!(!index.contains(Word::new("zebra").unwrap()));
+}
+
+#[test]
+fn answers_prefix_queries() {
+    let index = index(&["crane", "cranes", "crate", "slate"]);
+    assert_eq!(strings(index.with_prefix("cran")), ["crane", "cranes"]);
+    assert_eq!(strings(index.with_prefix("crane")), ["crane", "cranes"]);
+    assert_eq!(index.with_prefix("").len(), 4);
+    assert!(index.has_prefix("s"));
+    assert!(index.has_prefix("crat"));
+    assert!(!index.has_prefix("crx"));
+    assert!(!index.has_prefix("slates"));
+    assert!(!index.has_prefix("Cr"));
+}
+
+#[test]
+fn pattern_queries_agree_with_a_scan() {
+    let lists = WordLists::builtin();
+    for pattern in [
+        "s?a?e", "?????", "??ing", "qu???", "crane", "z?z?z", "s?a?es",
+    ] {
+        let expected: Vec<String> = lists
+            .answers
+            .words(pattern.len())
+            .iter()
+            .filter(|w| {
+                w.chars()
+                    .zip(pattern.chars())
+                    .all(|(c, p)| p == '?' || p == c)
+            })
+            .cloned()
+            .collect();
+        let mut found = strings(&lists.answers.matching(pattern));
+        let mut expected = expected;
+        expected.sort();
+        found.sort();
+        assert_eq!(found, expected, "{}", pattern);
+    }
+    assert!(strings(&lists.matching("S?A?E")).contains(&"scale".to_string()));
+}
+
+#[test]
+fn word_lists_accept_prefixes_of_either_list() {
+    let lists = WordLists::builtin();
+    assert!(lists.is_allowed_prefix("", 5));
+    assert!(lists.is_allowed_prefix("cra", 5));
+    assert!(lists.is_allowed_prefix("aahe", 5));
+    assert!(!lists.is_allowed_prefix("xq", 5));
+}
+
+#[test]
+fn prefixes_only_count_words_of_the_given_length() {
+    let lists = WordLists::builtin();
+    // Only longer words like "through" start with these.
+    assert!(lists.is_allowed_prefix("throu", 7));
+    assert!(!lists.is_allowed_prefix("throu", 5));
+    assert!(!lists.is_allowed_guess("throu"));
+    assert!(lists.is_allowed_prefix("thro", 5));
+    assert!(!lists.is_allowed_prefix("cra", 12));
+}